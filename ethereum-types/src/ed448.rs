@@ -0,0 +1,104 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{Address, Network, H1368, H160, H456};
+use fixed_hash::*;
+#[cfg(feature = "codec")]
+use impl_codec::impl_fixed_hash_codec;
+#[cfg(feature = "rlp")]
+use impl_rlp::impl_fixed_hash_rlp;
+#[cfg(feature = "serialize")]
+use impl_serde::impl_fixed_hash_serde;
+use tiny_keccak::{Hasher, Sha3};
+
+construct_fixed_hash! {
+	/// Ed448 public key.
+	#[cfg_attr(feature = "codec", derive(scale_info::TypeInfo))]
+	pub struct Ed448Public(57);
+}
+#[cfg(feature = "rlp")]
+impl_fixed_hash_rlp!(Ed448Public, 57);
+#[cfg(feature = "serialize")]
+impl_fixed_hash_serde!(Ed448Public, 57);
+#[cfg(feature = "codec")]
+impl_fixed_hash_codec!(Ed448Public, 57);
+
+construct_fixed_hash! {
+	/// Ed448 signature.
+	#[cfg_attr(feature = "codec", derive(scale_info::TypeInfo))]
+	pub struct Ed448Signature(171);
+}
+#[cfg(feature = "rlp")]
+impl_fixed_hash_rlp!(Ed448Signature, 171);
+#[cfg(feature = "serialize")]
+impl_fixed_hash_serde!(Ed448Signature, 171);
+#[cfg(feature = "codec")]
+impl_fixed_hash_codec!(Ed448Signature, 171);
+
+impl Ed448Public {
+	/// The account body of this key: the last 20 bytes of its SHA3-256 hash.
+	pub fn address_body(&self) -> H160 {
+		let mut hash = [0u8; 32];
+		let mut sha3 = Sha3::v256();
+		sha3.update(self.as_bytes());
+		sha3.finalize(&mut hash);
+		H160::from_slice(&hash[12..])
+	}
+
+	/// The address of this key on `network`.
+	pub fn to_address(&self, network: Network) -> Address {
+		network.address(&self.address_body())
+	}
+}
+
+macro_rules! impl_hash_conversions {
+	($name:ident, $hash:ident) => {
+		impl From<$hash> for $name {
+			fn from(hash: $hash) -> Self {
+				$name(hash.0)
+			}
+		}
+
+		impl From<$name> for $hash {
+			fn from(value: $name) -> Self {
+				$hash(value.0)
+			}
+		}
+	};
+}
+
+impl_hash_conversions!(Ed448Public, H456);
+impl_hash_conversions!(Ed448Signature, H1368);
+
+#[cfg(test)]
+mod tests {
+	use super::{Ed448Public, Ed448Signature};
+	use crate::{Address, IcanNetwork, Network, H1368, H160, H456};
+
+	#[test]
+	fn lengths() {
+		assert_eq!(Ed448Public::len_bytes(), 57);
+		assert_eq!(Ed448Signature::len_bytes(), 171);
+		assert_eq!(H456::from(Ed448Public::repeat_byte(1)), H456::repeat_byte(1));
+		assert_eq!(Ed448Signature::from(H1368::repeat_byte(2)), Ed448Signature::repeat_byte(2));
+	}
+
+	#[test]
+	fn address() {
+		let bytes: Vec<u8> = (0..57).collect();
+		let public = Ed448Public::from_slice(&bytes);
+		// SHA3-256 of the key is 6adc19a25346d39409c26446 followed by the body
+		let body: H160 = "6ac7ef7efe4a88e765a8beaa191266791a906064".parse().unwrap();
+		assert_eq!(public.address_body(), body);
+
+		let address = public.to_address(Network::Mainnet);
+		assert_eq!(address, Address::from_ican_str("cb786ac7ef7efe4a88e765a8beaa191266791a906064").unwrap());
+		assert_eq!(public.to_address(Network::Custom(9)).ican_network(), Some(IcanNetwork::Private));
+		assert_eq!(public.to_address(Network::Custom(9)).ican_body(), body);
+	}
+}
@@ -6,7 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{U128, U1368, U256, U456, U512, U64};
+use crate::{U128, U1368, U256, U448, U456, U512, U64};
 use fixed_hash::*;
 #[cfg(feature = "codec")]
 use impl_codec::impl_fixed_hash_codec;
@@ -41,7 +41,7 @@ impl_fixed_hash_serde!(H64, 8);
 #[cfg(feature = "codec")]
 impl_fixed_hash_codec!(H64, 8);
 
-pub use primitive_types::{H128, H1368, H160, H176, H256, H456};
+pub use primitive_types::{H128, H1368, H160, H176, H256, H384, H448, H456};
 
 construct_fixed_hash! {
 	#[cfg_attr(feature = "codec", derive(scale_info::TypeInfo))]
@@ -54,7 +54,7 @@ impl_fixed_hash_serde!(H264, 33);
 #[cfg(feature = "codec")]
 impl_fixed_hash_codec!(H264, 33);
 
-pub use primitive_types::H512;
+pub use primitive_types::{H512, H768};
 
 construct_fixed_hash! {
 	#[cfg_attr(feature = "codec", derive(scale_info::TypeInfo))]
@@ -88,14 +88,17 @@ macro_rules! impl_uint_conversions {
 impl_uint_conversions!(H64, U64);
 impl_uint_conversions!(H128, U128);
 impl_uint_conversions!(H256, U256);
+impl_uint_conversions!(H448, U448);
 impl_uint_conversions!(H456, U456);
 impl_uint_conversions!(H512, U512);
 impl_uint_conversions!(H1368, U1368);
 
 #[cfg(test)]
 mod tests {
-	use super::{H160, H256};
-	use crate::Address;
+	use super::{BigEndianHash, H160, H256, H384, H448, H768};
+	use crate::U448;
+	use crate::IcanNetwork;
+	use crate::{address, h160, h256, Address};
 	use serde_json as ser;
 
 	#[test]
@@ -119,14 +122,40 @@ mod tests {
 	#[test]
 	fn test_serialize_h176() {
 		let tests = vec![
-			(Address::from_low_u64_be(0), "00000000000000000000000000000000000000000000"),
-			(Address::from_low_u64_be(1_000), "000000000000000000000000000000000000000003e8"),
+			(Address::from_ican_parts(IcanNetwork::Private, &H160::zero()), "ce450000000000000000000000000000000000000000"),
+			(
+				Address::from_ican_parts(IcanNetwork::Mainnet, &H160::from_low_u64_be(1_000)),
+				"cb3000000000000000000000000000000000000003e8",
+			),
 		];
 
 		for (number, expected) in tests {
 			assert_eq!(format!("{:?}", expected), ser::to_string_pretty(&number).unwrap());
 			assert_eq!(number, ser::from_str(&format!("{:?}", expected)).unwrap());
 		}
+
+		// addresses are validated on deserialization
+		assert!(ser::from_str::<Address>(r#""00000000000000000000000000000000000000000000""#).is_err());
+		assert!(ser::from_str::<Address>(r#""ce460000000000000000000000000000000000000000""#).is_err());
+	}
+
+	#[test]
+	fn literals() {
+		const ADDRESS: Address = address!("0xab792215c43fc213c02182c8389f2bc32408e2c50922");
+		assert_eq!(ADDRESS, Address::from_ican_str("ab792215c43fc213c02182c8389f2bc32408e2c50922").unwrap());
+		assert_eq!(h160!("00000000000000000000000000000000000003e8"), H160::from_low_u64_be(1_000));
+		assert_eq!(
+			h256!("0x00000000000000000000000000000000000000000000000000000000000003e8"),
+			H256::from_low_u64_be(1_000)
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "checksum mismatch")]
+	fn address_literal_checksum() {
+		let mut bytes = [0u8; 22];
+		bytes[0] = 0xce;
+		Address::from_ican_literal(bytes);
 	}
 
 	#[test]
@@ -150,6 +179,29 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_serialize_wide_hashes() {
+		let h384 = H384::repeat_byte(0x38);
+		assert_eq!(ser::to_string(&h384).unwrap(), format!("\"0x{}\"", "38".repeat(48)));
+		assert_eq!(ser::from_str::<H384>(&ser::to_string(&h384).unwrap()).unwrap(), h384);
+
+		let h448 = H448::repeat_byte(0x44);
+		assert_eq!(ser::to_string(&h448).unwrap(), format!("\"0x{}\"", "44".repeat(56)));
+		assert_eq!(ser::from_str::<H448>(&ser::to_string(&h448).unwrap()).unwrap(), h448);
+
+		let h768 = H768::repeat_byte(0x76);
+		assert_eq!(ser::from_str::<H768>(&ser::to_string(&h768).unwrap()).unwrap(), h768);
+	}
+
+	#[test]
+	fn h448_uint_roundtrip() {
+		let value = U448::MAX - 1_000;
+		let hash = H448::from_uint(&value);
+		assert_eq!(hash.as_bytes()[55], 0x17);
+		assert_eq!(hash.into_uint(), value);
+		assert_eq!(H448::from_uint(&U448::from(1)), H448::from_low_u64_be(1));
+	}
+
 	#[test]
 	fn test_parse_0x() {
 		assert!("0x0000000000000000000000000000000000000000000000000000000000000000"
@@ -8,19 +8,80 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod ed448;
 mod hash;
+mod network;
+mod secret;
 mod uint;
 
 #[cfg(feature = "ethbloom")]
 pub use ethbloom::{Bloom, BloomRef, Input as BloomInput};
-pub use hash::{BigEndianHash, H128, H1368, H160, H176, H256, H264, H32, H456, H512, H520, H64};
-pub use uint::{FromDecStrErr, FromStrRadixErr, FromStrRadixErrKind, U128, U1368, U256, U456, U512, U64};
+pub use ed448::{Ed448Public, Ed448Signature};
+pub use hash::{BigEndianHash, H128, H1368, H160, H176, H256, H264, H32, H384, H448, H456, H512, H520, H64, H768};
+pub use network::{FromNetworkStrErr, Network};
+pub use primitive_types::{IcanError, IcanNetwork};
+pub use secret::{Ed448Secret, Secret};
+pub use uint::{FromDecStrErr, FromStrRadixErr, FromStrRadixErrKind, I128, I256, I512, U1024, U128, U1368, U256, U448, U456, U512, U64};
 
 pub type Address = H176;
-pub type Secret = H256;
+/// Ethereum-era 64-byte public key. Core keys are [`Ed448Public`].
+#[cfg(feature = "legacy-aliases")]
 pub type Public = H512;
+/// Ethereum-era 65-byte ECDSA signature. Core signatures are [`Ed448Signature`].
+#[cfg(feature = "legacy-aliases")]
 pub type Signature = H520;
 
+#[doc(hidden)]
+pub use fixed_hash as fixed_hash_;
+
+/// Creates an [`H160`] constant from a hex string literal, checked at compile time.
+#[macro_export]
+macro_rules! h160 {
+	($hex:expr) => {
+		$crate::fixed_hash_::hash!($crate::H160, $hex)
+	};
+}
+
+/// Creates an [`H256`] constant from a hex string literal, checked at compile time.
+///
+/// ```
+/// use ethereum_types::{h256, H256};
+///
+/// const ZERO: H256 = h256!("0x0000000000000000000000000000000000000000000000000000000000000000");
+/// assert!(ZERO.is_zero());
+/// ```
+#[macro_export]
+macro_rules! h256 {
+	($hex:expr) => {
+		$crate::fixed_hash_::hash!($crate::H256, $hex)
+	};
+}
+
+/// Creates an [`Address`] constant from a hex string literal.
+///
+/// Besides the length and characters, the network prefix and check digits are
+/// validated at compile time.
+///
+/// ```
+/// use ethereum_types::{address, Address, IcanNetwork};
+///
+/// const TREASURY: Address = address!("cb57bbbb54cdf60fa666fd741be78f794d4608d67109");
+/// assert_eq!(TREASURY.ican_network(), Some(IcanNetwork::Mainnet));
+/// ```
+///
+/// A wrong check digit does not compile:
+///
+/// ```compile_fail
+/// let address = ethereum_types::address!("cb58bbbb54cdf60fa666fd741be78f794d4608d67109");
+/// ```
+#[macro_export]
+macro_rules! address {
+	($hex:expr) => {{
+		const VALUE: $crate::Address = $crate::Address::from_ican_literal($crate::fixed_hash_::decode_hex($hex));
+		VALUE
+	}};
+}
+
 /// Conditional compilation depending on whether ethereum-types is built with ethbloom support.
 #[cfg(feature = "ethbloom")]
 #[macro_export]
@@ -0,0 +1,249 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{Address, IcanNetwork, H160};
+use core::{fmt, hash, str::FromStr};
+
+/// Network identifier, as used in transaction signing and peer handshakes.
+///
+/// Known ids map to their own variant and any other id is a private network.
+/// Build values from ids with `From<u64>` so `Custom` never holds a known id;
+/// comparison and hashing go by [`Network::id`] either way.
+#[derive(Debug, Clone, Copy)]
+pub enum Network {
+	/// Mainnet, network id 1.
+	Mainnet,
+	/// Testnet, network id 3.
+	Testnet,
+	/// Any other network id.
+	Custom(u64),
+}
+
+impl Network {
+	/// The numeric network id.
+	pub const fn id(self) -> u64 {
+		match self {
+			Network::Mainnet => 1,
+			Network::Testnet => 3,
+			Network::Custom(id) => id,
+		}
+	}
+
+	/// The network addresses on this network are prefixed with. Custom
+	/// networks all use the private prefix.
+	pub const fn ican_network(self) -> IcanNetwork {
+		match Network::from_id(self.id()) {
+			Network::Mainnet => IcanNetwork::Mainnet,
+			Network::Testnet => IcanNetwork::Testnet,
+			Network::Custom(_) => IcanNetwork::Private,
+		}
+	}
+
+	/// The address prefix byte for this network.
+	pub const fn ican_prefix(self) -> u8 {
+		self.ican_network().prefix()
+	}
+
+	/// The address of `body` on this network.
+	pub fn address(self, body: &H160) -> Address {
+		Address::from_ican_parts(self.ican_network(), body)
+	}
+
+	const fn from_id(id: u64) -> Self {
+		match id {
+			1 => Network::Mainnet,
+			3 => Network::Testnet,
+			id => Network::Custom(id),
+		}
+	}
+}
+
+impl From<u64> for Network {
+	fn from(id: u64) -> Self {
+		Network::from_id(id)
+	}
+}
+
+impl From<Network> for u64 {
+	fn from(network: Network) -> u64 {
+		network.id()
+	}
+}
+
+impl PartialEq for Network {
+	fn eq(&self, other: &Self) -> bool {
+		self.id() == other.id()
+	}
+}
+
+impl Eq for Network {}
+
+impl hash::Hash for Network {
+	fn hash<H: hash::Hasher>(&self, state: &mut H) {
+		self.id().hash(state)
+	}
+}
+
+/// Writes `mainnet` and `testnet` by name and other networks as their id.
+impl fmt::Display for Network {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match Network::from_id(self.id()) {
+			Network::Mainnet => f.write_str("mainnet"),
+			Network::Testnet => f.write_str("testnet"),
+			Network::Custom(id) => write!(f, "{}", id),
+		}
+	}
+}
+
+/// Error parsing a [`Network`] from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FromNetworkStrErr;
+
+impl fmt::Display for FromNetworkStrErr {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("expected a network name or a decimal network id")
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromNetworkStrErr {}
+
+/// Parses a case-insensitive network name or a decimal network id.
+impl FromStr for Network {
+	type Err = FromNetworkStrErr;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if s.eq_ignore_ascii_case("mainnet") {
+			Ok(Network::Mainnet)
+		} else if s.eq_ignore_ascii_case("testnet") {
+			Ok(Network::Testnet)
+		} else {
+			s.parse::<u64>().map(Network::from).map_err(|_| FromNetworkStrErr)
+		}
+	}
+}
+
+#[cfg(feature = "rlp")]
+impl impl_rlp::rlp::Encodable for Network {
+	fn rlp_append(&self, s: &mut impl_rlp::rlp::RlpStream) {
+		s.append(&self.id());
+	}
+}
+
+#[cfg(feature = "rlp")]
+impl impl_rlp::rlp::Decodable for Network {
+	fn decode(rlp: &impl_rlp::rlp::Rlp) -> Result<Self, impl_rlp::rlp::DecoderError> {
+		rlp.as_val::<u64>().map(Network::from)
+	}
+}
+
+/// Serializes as the numeric id. Deserializes from the id or from anything
+/// [`FromStr`] accepts.
+#[cfg(feature = "serialize")]
+impl impl_serde::serde::Serialize for Network {
+	fn serialize<S: impl_serde::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_u64(self.id())
+	}
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> impl_serde::serde::Deserialize<'de> for Network {
+	fn deserialize<D: impl_serde::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		use impl_serde::serde::de;
+
+		struct NetworkVisitor;
+
+		impl<'a> de::Visitor<'a> for NetworkVisitor {
+			type Value = Network;
+
+			fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+				write!(formatter, "a network id or name")
+			}
+
+			fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+				Ok(Network::from(v))
+			}
+
+			fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+				v.parse().map_err(E::custom)
+			}
+		}
+
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_any(NetworkVisitor)
+		} else {
+			deserializer.deserialize_u64(NetworkVisitor)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Network;
+	use crate::{IcanNetwork, H160};
+
+	#[test]
+	fn ids() {
+		assert_eq!(Network::from(1), Network::Mainnet);
+		assert_eq!(Network::from(3), Network::Testnet);
+		assert_eq!(Network::from(7), Network::Custom(7));
+		assert_eq!(Network::Custom(1), Network::Mainnet);
+		assert_eq!(u64::from(Network::Testnet), 3);
+	}
+
+	#[test]
+	fn ican() {
+		assert_eq!(Network::Mainnet.ican_network(), IcanNetwork::Mainnet);
+		assert_eq!(Network::Testnet.ican_prefix(), 0xab);
+		assert_eq!(Network::Custom(1337).ican_network(), IcanNetwork::Private);
+		assert_eq!(Network::Custom(1).ican_network(), IcanNetwork::Mainnet);
+
+		let address = Network::Mainnet.address(&H160::from_low_u64_be(1_000));
+		assert_eq!(address.to_string(), "cb3000000000000000000000000000000000000003e8");
+		assert_eq!(address.ican_network(), Some(IcanNetwork::Mainnet));
+	}
+
+	#[test]
+	fn from_str_display() {
+		for (network, s) in [(Network::Mainnet, "mainnet"), (Network::Testnet, "testnet"), (Network::Custom(5), "5")] {
+			assert_eq!(network.to_string(), s);
+			assert_eq!(s.parse::<Network>(), Ok(network));
+		}
+		assert_eq!("MainNet".parse::<Network>(), Ok(Network::Mainnet));
+		assert_eq!("1".parse::<Network>(), Ok(Network::Mainnet));
+		assert!("devnet".parse::<Network>().is_err());
+		assert!("-1".parse::<Network>().is_err());
+		assert!("".parse::<Network>().is_err());
+	}
+
+	#[cfg(feature = "rlp")]
+	#[test]
+	fn rlp() {
+		use impl_rlp::rlp;
+
+		for network in [Network::Mainnet, Network::Testnet, Network::Custom(0), Network::Custom(u64::MAX)] {
+			let encoded = rlp::encode(&network);
+			assert_eq!(encoded, rlp::encode(&network.id()));
+			assert_eq!(rlp::decode::<Network>(&encoded), Ok(network));
+		}
+	}
+
+	#[cfg(feature = "serialize")]
+	#[test]
+	fn serde() {
+		use serde_json as ser;
+
+		assert_eq!(ser::to_string(&Network::Mainnet).unwrap(), "1");
+		assert_eq!(ser::to_string(&Network::Custom(1337)).unwrap(), "1337");
+		assert_eq!(ser::from_str::<Network>("3").unwrap(), Network::Testnet);
+		assert_eq!(ser::from_str::<Network>(r#""testnet""#).unwrap(), Network::Testnet);
+		assert_eq!(ser::from_str::<Network>(r#""1337""#).unwrap(), Network::Custom(1337));
+		assert!(ser::from_str::<Network>(r#""devnet""#).is_err());
+		assert!(ser::from_str::<Network>("-1").is_err());
+	}
+}
@@ -0,0 +1,197 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{H256, H456};
+use core::{fmt, ptr, str::FromStr, sync::atomic};
+
+macro_rules! construct_secret {
+	($(#[$attr:meta])* $name:ident, $hash:ident, $n_bytes:expr) => {
+		$(#[$attr])*
+		#[derive(Clone, Default)]
+		pub struct $name($hash);
+
+		impl $name {
+			/// Creates a secret from the given bytes.
+			///
+			/// # Panics
+			///
+			#[doc = concat!("If the length of `src` is not ", stringify!($n_bytes), ".")]
+			pub fn from_slice(src: &[u8]) -> Self {
+				$name($hash::from_slice(src))
+			}
+
+			/// Returns the secret value. Take care not to leave copies of it around.
+			pub fn expose(&self) -> &$hash {
+				&self.0
+			}
+
+			/// Returns `true` if the secret is all zeros.
+			pub fn is_zero(&self) -> bool {
+				self.0.is_zero()
+			}
+		}
+
+		impl From<$hash> for $name {
+			fn from(secret: $hash) -> Self {
+				$name(secret)
+			}
+		}
+
+		impl From<[u8; $n_bytes]> for $name {
+			fn from(bytes: [u8; $n_bytes]) -> Self {
+				$name($hash(bytes))
+			}
+		}
+
+		impl FromStr for $name {
+			type Err = <$hash as FromStr>::Err;
+
+			fn from_str(s: &str) -> Result<Self, Self::Err> {
+				$hash::from_str(s).map($name)
+			}
+		}
+
+		/// Constant time comparison.
+		impl PartialEq for $name {
+			fn eq(&self, other: &Self) -> bool {
+				self.0.ct_eq_bool(&other.0)
+			}
+		}
+
+		impl Eq for $name {}
+
+		impl fmt::Debug for $name {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str(concat!(stringify!($name), "(<redacted>)"))
+			}
+		}
+
+		impl fmt::Display for $name {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("<redacted>")
+			}
+		}
+
+		impl Drop for $name {
+			fn drop(&mut self) {
+				for byte in self.0.as_bytes_mut() {
+					// volatile so the writes to memory about to be freed are not optimized out
+					unsafe { ptr::write_volatile(byte, 0) };
+				}
+				atomic::compiler_fence(atomic::Ordering::SeqCst);
+			}
+		}
+
+		#[cfg(feature = "zeroize")]
+		impl zeroize::Zeroize for $name {
+			fn zeroize(&mut self) {
+				self.0.zeroize();
+			}
+		}
+
+		#[cfg(feature = "zeroize")]
+		impl zeroize::ZeroizeOnDrop for $name {}
+
+		#[cfg(feature = "subtle")]
+		impl subtle::ConstantTimeEq for $name {
+			fn ct_eq(&self, other: &Self) -> subtle::Choice {
+				subtle::ConstantTimeEq::ct_eq(&self.0, &other.0)
+			}
+		}
+
+		#[cfg(feature = "serialize-secret")]
+		impl impl_serde::serde::Serialize for $name {
+			fn serialize<S: impl_serde::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				self.0.serialize(serializer)
+			}
+		}
+
+		#[cfg(feature = "serialize-secret")]
+		impl<'de> impl_serde::serde::Deserialize<'de> for $name {
+			fn deserialize<D: impl_serde::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				$hash::deserialize(deserializer).map($name)
+			}
+		}
+	};
+}
+
+construct_secret! {
+	/// 256-bit secret, such as a private key.
+	///
+	/// Unlike a plain `H256` it is cleared from memory when dropped, compares in
+	/// constant time and never prints its contents. It only implements serde with
+	/// the `serialize-secret` feature.
+	Secret, H256, 32
+}
+
+construct_secret! {
+	/// Ed448 secret key, with the same protections as [`Secret`].
+	Ed448Secret, H456, 57
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Ed448Secret, Secret};
+	use crate::{H256, H456};
+
+	#[test]
+	fn redacted_formatting() {
+		let secret = Secret::from(H256::repeat_byte(0xab));
+		assert_eq!(format!("{:?}", secret), "Secret(<redacted>)");
+		assert_eq!(format!("{}", secret), "<redacted>");
+		assert!(!format!("{:?}", Some(&secret)).contains("ab"));
+	}
+
+	#[test]
+	fn equality() {
+		let a: Secret = "0x0101010101010101010101010101010101010101010101010101010101010101".parse().unwrap();
+		assert_eq!(a, Secret::from([1; 32]));
+		assert_ne!(a, Secret::default());
+		assert!(Secret::default().is_zero());
+		assert_eq!(a.expose(), &H256::repeat_byte(1));
+		assert_eq!(a.clone(), a);
+	}
+
+	#[test]
+	fn cleared_on_drop() {
+		let mut secret = core::mem::ManuallyDrop::new(Secret::from_slice(&[0x42; 32]));
+		let bytes = secret.0.as_ptr();
+		unsafe {
+			core::mem::ManuallyDrop::drop(&mut secret);
+			// the memory is still owned by `secret`, just dropped in place
+			assert!((0..32).all(|i| *bytes.add(i) == 0));
+		}
+	}
+
+	#[test]
+	fn ed448_secret() {
+		let secret = Ed448Secret::from_slice(&[9; 57]);
+		assert_eq!(format!("{:?}", secret), "Ed448Secret(<redacted>)");
+		assert_eq!(secret, Ed448Secret::from(H456::repeat_byte(9)));
+		assert_ne!(secret, Ed448Secret::default());
+	}
+
+	#[cfg(feature = "zeroize")]
+	#[test]
+	fn zeroize() {
+		use zeroize::Zeroize;
+
+		let mut secret = Secret::from([7; 32]);
+		secret.zeroize();
+		assert!(secret.is_zero());
+	}
+
+	#[cfg(feature = "serialize-secret")]
+	#[test]
+	fn serde() {
+		let secret = Secret::from(H256::from_low_u64_be(1));
+		let json = serde_json::to_string(&secret).unwrap();
+		assert_eq!(json, r#""0x0000000000000000000000000000000000000000000000000000000000000001""#);
+		assert_eq!(serde_json::from_str::<Secret>(&json).unwrap(), secret);
+	}
+}
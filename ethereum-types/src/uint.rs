@@ -27,11 +27,13 @@ impl_uint_serde!(U64, 1);
 #[cfg(feature = "codec")]
 impl_uint_codec!(U64, 1);
 
-pub use primitive_types::{U128, U1368, U256, U456, U512};
+pub use primitive_types::{I128, I256, I512, U1024, U128, U1368, U256, U448, U456, U512};
 
 #[cfg(test)]
 mod tests {
-	use super::{U256, U512};
+	use super::{U1024, U128, U1368, U256, U448, U456, U512};
+	use core::convert::TryFrom;
+	use primitive_types::Error;
 	use serde_json as ser;
 	use std::u64::MAX;
 
@@ -86,7 +88,9 @@ mod tests {
 	}
 
 	test_serialize!(U256, test_u256);
+	test_serialize!(U448, test_u448);
 	test_serialize!(U512, test_u512);
+	test_serialize!(U1368, test_u1368);
 
 	#[test]
 	fn test_serialize_large_values() {
@@ -212,4 +216,40 @@ mod tests {
 		let result = U256([1, 2, 3, 4]).full_mul(U256([5, 6, 7, 8]));
 		assert_eq!(U512([5, 16, 34, 60, 61, 52, 32, 0]), result);
 	}
+
+	#[test]
+	fn full_mul_for_every_width() {
+		assert_eq!(U128::MAX.full_mul(U128::MAX), U256::from(U128::MAX) * U256::from(U128::MAX));
+		assert_eq!(U448::MAX.full_mul(U448::MAX), U1024::from(U448::MAX) * U1024::from(U448::MAX));
+		assert_eq!(U456::MAX.full_mul(U456::from(2)), U1024::from(U456::MAX) << 1);
+		assert_eq!(U512::MAX.full_mul(U512::MAX), U1024::from(U512::MAX) * U1024::from(U512::MAX));
+		assert_eq!(U512::from(7).full_mul(U512::from(6)), U1024::from(42));
+
+		let (low, high) = U1024::MAX.widening_mul(U1024::MAX);
+		assert_eq!(low, U1024::one());
+		assert_eq!(high, U1024::MAX - 1);
+		let (low, high) = U1368::MAX.widening_mul(U1368::from(2));
+		assert_eq!(low, U1368::MAX - 1);
+		assert_eq!(high, U1368::one());
+	}
+
+	#[test]
+	fn widening_conversions() {
+		let x = U128::MAX;
+		assert_eq!(U1368::from(U1024::from(U512::from(U456::from(U448::from(U256::from(x)))))), U1368::from(x));
+		assert_eq!(U1368::from(&U448::MAX).0[..7], U448::MAX.0[..]);
+		assert_eq!(U1024::from(U456::MAX).0[8..], [0; 8]);
+	}
+
+	#[test]
+	fn narrowing_conversions() {
+		assert_eq!(U128::try_from(U1368::from(u64::MAX)), Ok(U128::from(u64::MAX)));
+		assert_eq!(U128::try_from(U1368::from(U128::MAX) + 1), Err(Error::Overflow));
+		assert_eq!(U448::try_from(&U1024::from(U448::MAX)), Ok(U448::MAX));
+		assert_eq!(U448::try_from(U512::from(U448::MAX) + 1), Err(Error::Overflow));
+		assert_eq!(U512::try_from(U1368::MAX), Err(Error::Overflow));
+		assert_eq!(U1024::try_from(U1368::from(U1024::MAX)), Ok(U1024::MAX));
+		// same number of words
+		assert_eq!(U456::try_from(U512::MAX), Ok(U456::MAX));
+	}
 }
@@ -0,0 +1,112 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Base58, base64 and bech32m encodings behind the `bs58`, `base64` and
+//! `bech32` features.
+
+#[cfg(any(feature = "bs58", feature = "base64", feature = "bech32"))]
+use crate::alloc_::{string::String, vec::Vec};
+use core::fmt;
+
+/// Error decoding a fixed hash from base58, base64 or bech32m.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromEncodedStrErr {
+	/// The string is not valid in the encoding.
+	InvalidEncoding,
+	/// The string decodes to the wrong number of bytes.
+	InvalidLength {
+		/// Bytes the hash type needs.
+		expected: usize,
+		/// Bytes decoded.
+		actual: usize,
+	},
+	/// The bech32m checksum does not match.
+	InvalidChecksum,
+	/// The bech32m human-readable part is not the expected one.
+	UnexpectedPrefix,
+}
+
+impl fmt::Display for FromEncodedStrErr {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			FromEncodedStrErr::InvalidEncoding => f.write_str("invalid encoding"),
+			FromEncodedStrErr::InvalidLength { expected, actual } => {
+				write!(f, "invalid length: expected {} bytes, got {}", expected, actual)
+			},
+			FromEncodedStrErr::InvalidChecksum => f.write_str("invalid checksum"),
+			FromEncodedStrErr::UnexpectedPrefix => f.write_str("unexpected human-readable part"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromEncodedStrErr {}
+
+#[cfg(any(feature = "bs58", feature = "base64", feature = "bech32"))]
+fn copy_exact(decoded: &[u8], bytes: &mut [u8]) -> Result<(), FromEncodedStrErr> {
+	if decoded.len() != bytes.len() {
+		return Err(FromEncodedStrErr::InvalidLength { expected: bytes.len(), actual: decoded.len() })
+	}
+	bytes.copy_from_slice(decoded);
+	Ok(())
+}
+
+#[cfg(feature = "bs58")]
+#[doc(hidden)]
+pub fn to_base58(bytes: &[u8]) -> String {
+	bs58::encode(bytes).into_string()
+}
+
+#[cfg(feature = "bs58")]
+#[doc(hidden)]
+pub fn from_base58(input: &str, bytes: &mut [u8]) -> Result<(), FromEncodedStrErr> {
+	let decoded: Vec<u8> = bs58::decode(input).into_vec().map_err(|_| FromEncodedStrErr::InvalidEncoding)?;
+	copy_exact(&decoded, bytes)
+}
+
+#[cfg(feature = "base64")]
+#[doc(hidden)]
+pub fn to_base64(bytes: &[u8]) -> String {
+	use base64::Engine;
+	base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+#[cfg(feature = "base64")]
+#[doc(hidden)]
+pub fn from_base64(input: &str, bytes: &mut [u8]) -> Result<(), FromEncodedStrErr> {
+	use base64::Engine;
+	let decoded = base64::engine::general_purpose::STANDARD
+		.decode(input)
+		.map_err(|_| FromEncodedStrErr::InvalidEncoding)?;
+	copy_exact(&decoded, bytes)
+}
+
+#[cfg(feature = "bech32")]
+#[doc(hidden)]
+pub fn to_bech32(hrp: &str, bytes: &[u8]) -> String {
+	use bech32::ToBase32;
+	bech32::encode(hrp, bytes.to_base32(), bech32::Variant::Bech32m).expect("invalid bech32 human-readable part")
+}
+
+#[cfg(feature = "bech32")]
+#[doc(hidden)]
+pub fn from_bech32(hrp: &str, input: &str, bytes: &mut [u8]) -> Result<(), FromEncodedStrErr> {
+	use bech32::FromBase32;
+	let (prefix, data, variant) = bech32::decode(input).map_err(|err| match err {
+		bech32::Error::InvalidChecksum => FromEncodedStrErr::InvalidChecksum,
+		_ => FromEncodedStrErr::InvalidEncoding,
+	})?;
+	if variant != bech32::Variant::Bech32m {
+		return Err(FromEncodedStrErr::InvalidChecksum)
+	}
+	if !prefix.eq_ignore_ascii_case(hrp) {
+		return Err(FromEncodedStrErr::UnexpectedPrefix)
+	}
+	let decoded = Vec::<u8>::from_base32(&data).map_err(|_| FromEncodedStrErr::InvalidEncoding)?;
+	copy_exact(&decoded, bytes)
+}
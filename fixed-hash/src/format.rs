@@ -0,0 +1,165 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Hex formatting shared by all fixed hash types.
+//!
+//! Everything here honours the width, fill, alignment and `0` flag of the
+//! formatter. Padding goes on the left unless another alignment is asked for,
+//! like for integers, and the `0` flag pads with zeros after the prefix.
+
+use core::fmt::{self, Alignment, Formatter, Write};
+
+const LOWER: &[u8; 16] = b"0123456789abcdef";
+const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Writes `bytes` as hex, with a `0x` prefix if `prefix` is set.
+#[doc(hidden)]
+pub fn write_hex(f: &mut Formatter, bytes: &[u8], prefix: bool, upper: bool) -> fmt::Result {
+	let digits = if upper { UPPER } else { LOWER };
+	pad(f, prefix, bytes.len() * 2, |f| write_digits(f, bytes, |_, nibble| digits[nibble as usize] as char))
+}
+
+/// Writes the first `leading` and last `trailing` bytes of `bytes` as hex with
+/// `…` in between, or all of them if that would not be shorter.
+#[doc(hidden)]
+pub fn write_abbreviated(
+	f: &mut Formatter,
+	bytes: &[u8],
+	prefix: bool,
+	leading: usize,
+	trailing: usize,
+) -> fmt::Result {
+	if leading.saturating_add(trailing) >= bytes.len() {
+		return write_hex(f, bytes, prefix, false)
+	}
+	let tail = bytes.len() - trailing;
+	pad(f, prefix, (leading + trailing) * 2 + 1, |f| {
+		write_digits(f, &bytes[..leading], |_, nibble| LOWER[nibble as usize] as char)?;
+		f.write_char('…')?;
+		write_digits(f, &bytes[tail..], |_, nibble| LOWER[nibble as usize] as char)
+	})
+}
+
+/// Abbreviated hex display of a fixed hash, returned by its `abbreviated` method.
+///
+/// Shows the given number of leading and trailing bytes with `…` in between.
+/// The `0x` prefix is written for hash types that print one in `Display`.
+#[derive(Debug, Clone, Copy)]
+pub struct Abbreviated<'a> {
+	bytes: &'a [u8],
+	prefix: bool,
+	leading: usize,
+	trailing: usize,
+}
+
+impl<'a> Abbreviated<'a> {
+	#[doc(hidden)]
+	pub fn new(bytes: &'a [u8], prefix: bool, leading: usize, trailing: usize) -> Self {
+		Abbreviated { bytes, prefix, leading, trailing }
+	}
+}
+
+impl fmt::Display for Abbreviated<'_> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write_abbreviated(f, self.bytes, self.prefix, self.leading, self.trailing)
+	}
+}
+
+/// Checksummed mixed-case hex of a fixed hash, returned by its `checksummed` method.
+///
+/// Like EIP-55, the digest of the lowercase hex digits decides the case: a
+/// letter is uppercase if the nibble of the digest at the same position is 8
+/// or more. Digests shorter than the hex string are reused from the start.
+/// `{:#}` adds a `0x` prefix.
+#[derive(Debug, Clone, Copy)]
+pub struct Checksummed<'a> {
+	bytes: &'a [u8],
+	digest: [u8; 32],
+}
+
+impl<'a> Checksummed<'a> {
+	#[doc(hidden)]
+	pub fn new(bytes: &'a [u8], lower: &mut [u8], hash: impl FnOnce(&[u8]) -> [u8; 32]) -> Self {
+		for (pair, byte) in lower.chunks_mut(2).zip(bytes) {
+			pair[0] = LOWER[(byte >> 4) as usize];
+			pair[1] = LOWER[(byte & 0xf) as usize];
+		}
+		Checksummed { bytes, digest: hash(lower) }
+	}
+
+	/// Returns `true` if `s`, with or without a `0x` prefix, is exactly this
+	/// checksummed form.
+	pub fn matches(&self, s: &str) -> bool {
+		let s = s.strip_prefix("0x").unwrap_or(s).as_bytes();
+		s.len() == self.bytes.len() * 2 &&
+			s.iter().enumerate().all(|(i, &c)| {
+				let nibble = (self.bytes[i / 2] >> (4 * (1 - i % 2))) & 0xf;
+				c == self.digit(i, nibble) as u8
+			})
+	}
+
+	fn digit(&self, i: usize, nibble: u8) -> char {
+		let i = i % (self.digest.len() * 2);
+		let check = (self.digest[i / 2] >> (4 * (1 - i % 2))) & 0xf;
+		if check >= 8 {
+			UPPER[nibble as usize] as char
+		} else {
+			LOWER[nibble as usize] as char
+		}
+	}
+}
+
+impl fmt::Display for Checksummed<'_> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let prefix = f.alternate();
+		pad(f, prefix, self.bytes.len() * 2, |f| write_digits(f, self.bytes, |i, nibble| self.digit(i, nibble)))
+	}
+}
+
+// Writes two digits per byte, `digit` maps the digit position and nibble to a character.
+fn write_digits(f: &mut Formatter, bytes: &[u8], digit: impl Fn(usize, u8) -> char) -> fmt::Result {
+	for (i, byte) in bytes.iter().enumerate() {
+		f.write_char(digit(2 * i, byte >> 4))?;
+		f.write_char(digit(2 * i + 1, byte & 0xf))?;
+	}
+	Ok(())
+}
+
+// Writes the optional prefix and `body`, which is `len` characters long,
+// padded to the formatter's width.
+fn pad(
+	f: &mut Formatter,
+	prefix: bool,
+	len: usize,
+	body: impl FnOnce(&mut Formatter) -> fmt::Result,
+) -> fmt::Result {
+	let prefix = if prefix { "0x" } else { "" };
+	let padding = f.width().map_or(0, |width| width.saturating_sub(prefix.len() + len));
+	if f.sign_aware_zero_pad() {
+		f.write_str(prefix)?;
+		for _ in 0..padding {
+			f.write_char('0')?;
+		}
+		return body(f)
+	}
+	let (before, after) = match f.align() {
+		Some(Alignment::Left) => (0, padding),
+		Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+		Some(Alignment::Right) | None => (padding, 0),
+	};
+	let fill = f.fill();
+	for _ in 0..before {
+		f.write_char(fill)?;
+	}
+	f.write_str(prefix)?;
+	body(f)?;
+	for _ in 0..after {
+		f.write_char(fill)?;
+	}
+	Ok(())
+}
@@ -41,7 +41,7 @@
 ///
 /// The second argument is used to control whether the hash type should be prefixed with `0x` when
 /// formatting it as a string. When it is passed - no 0x printed, when it is not passed - 0x is
-/// printed.
+/// printed. It affects `Debug` and `Display`, while `{:#x}` and `{:#X}` always print the prefix.
 #[macro_export(local_inner_macros)]
 macro_rules! construct_fixed_hash {
 	( $(#[$attr:meta])* $visibility:vis struct $name:ident ( $n_bytes:expr $(, $cut_prefix:expr)? ); ) => {
@@ -210,73 +210,186 @@ macro_rules! construct_fixed_hash {
 			pub fn is_zero(&self) -> bool {
 				self.as_bytes().iter().all(|&byte| byte == 0u8)
 			}
+
+			/// Returns `true` if `self` and `other` are equal, taking the same time
+			/// whatever their contents. Use this instead of `==` for secrets.
+			#[inline]
+			pub fn ct_eq_bool(&self, other: &Self) -> bool {
+				let diff = self.0.iter().zip(other.0.iter()).fold(0u8, |acc, (a, b)| acc | (a ^ b));
+				// keep the compiler from turning the fold into an early-exit comparison
+				// SAFETY: `diff` is a local, so the pointer is valid, aligned and
+				// points to an initialized value.
+				unsafe { $crate::core_::ptr::read_volatile(&diff) == 0 }
+			}
+
+			/// Returns the XOR distance between `self` and `other`, as used by
+			/// Kademlia style routing.
+			#[inline]
+			pub fn xor_distance(&self, other: &Self) -> Self {
+				self ^ other
+			}
+
+			/// Returns the number of leading zero bits.
+			#[inline]
+			pub fn leading_zeros(&self) -> u32 {
+				let mut zeros = 0;
+				for &byte in self.as_bytes() {
+					zeros += byte.leading_zeros();
+					if byte != 0 {
+						break
+					}
+				}
+				zeros
+			}
+
+			/// Returns the number of leading bits `self` and `other` have in common.
+			#[inline]
+			pub fn common_prefix_len(&self, other: &Self) -> u32 {
+				self.xor_distance(other).leading_zeros()
+			}
+
+			/// Returns the base 2 logarithm of the XOR distance to `other`, rounded
+			/// down, or `None` if the hashes are equal.
+			///
+			/// This is the index of the Kademlia bucket `other` falls into, from 0
+			/// for the closest bucket to `len_bytes() * 8 - 1`.
+			#[inline]
+			pub fn log2_distance(&self, other: &Self) -> Option<u32> {
+				let common = self.common_prefix_len(other);
+				let bits = ($n_bytes * 8) as u32;
+				if common == bits {
+					None
+				} else {
+					Some(bits - 1 - common)
+				}
+			}
+
+			/// Compares the XOR distances of `a` and `b` to `self`. Sorting with it
+			/// puts the hashes closest to `self` first.
+			#[inline]
+			pub fn cmp_distance(&self, a: &Self, b: &Self) -> $crate::core_::cmp::Ordering {
+				for ((t, a), b) in self.0.iter().zip(a.0.iter()).zip(b.0.iter()) {
+					match (t ^ a).cmp(&(t ^ b)) {
+						$crate::core_::cmp::Ordering::Equal => continue,
+						ordering => return ordering,
+					}
+				}
+				$crate::core_::cmp::Ordering::Equal
+			}
+
+			/// Returns bit `index`, counting from the most significant bit of the
+			/// first byte like [`Self::leading_zeros`] does.
+			///
+			/// # Panics
+			///
+			/// If `index` is not less than `len_bytes() * 8`.
+			#[inline]
+			pub fn bit(&self, index: usize) -> bool {
+				self.0[index / 8] & (0x80 >> (index % 8)) != 0
+			}
+
+			/// Sets bit `index`, counted as in [`Self::bit`], to `value`.
+			///
+			/// # Panics
+			///
+			/// If `index` is not less than `len_bytes() * 8`.
+			#[inline]
+			pub fn set_bit(&mut self, index: usize, value: bool) {
+				let mask = 0x80 >> (index % 8);
+				if value {
+					self.0[index / 8] |= mask;
+				} else {
+					self.0[index / 8] &= !mask;
+				}
+			}
 		}
 
+		impl $name {
+			// Whether `Debug` and `Display` write a `0x` prefix.
+			const HEX_PREFIX: bool = true $(&& { let _ = $cut_prefix; false })?;
+
+			/// Formats the first `leading` and the last `trailing` bytes as hex with
+			/// `…` in between, like `Display` does with two bytes on each side.
+			pub fn abbreviated(&self, leading: usize, trailing: usize) -> $crate::Abbreviated<'_> {
+				$crate::Abbreviated::new(&self.0, Self::HEX_PREFIX, leading, trailing)
+			}
+
+			/// Formats the hash as mixed-case hex, the case of each letter checking
+			/// the value like in EIP-55.
+			///
+			/// `hash` is applied to the lowercase hex digits without prefix. For
+			/// EIP-55 it is Keccak-256.
+			pub fn checksummed(&self, hash: impl FnOnce(&[u8]) -> [u8; 32]) -> $crate::Checksummed<'_> {
+				let mut lower = [0u8; $n_bytes * 2];
+				$crate::Checksummed::new(&self.0, &mut lower, hash)
+			}
+		}
+
+		/// Full hex, with a `0x` prefix unless the type is declared without one.
 		impl $crate::core_::fmt::Debug for $name {
 			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
-				$crate::core_::write!(f, "{:#x}", self)
+				$crate::format::write_hex(f, &self.0, Self::HEX_PREFIX, false)
 			}
 		}
 
-		#[allow(unreachable_code)]
+		/// Types with a `0x` prefix show two bytes on each side, types without
+		/// one the full hex. A precision, as in `{:.4}`, shows that many bytes on
+		/// each side.
 		impl $crate::core_::fmt::Display for $name {
 			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
-				$($cut_prefix;
-					for i in &self.0 {
-						$crate::core_::write!(f, "{:02x}", i)?;
-					}
-					return Ok(());
-				)?
-
-				$crate::core_::write!(f, "0x")?;
-				for i in &self.0[0..2] {
-					$crate::core_::write!(f, "{:02x}", i)?;
+				let prefix = Self::HEX_PREFIX;
+				match f.precision() {
+					Some(n) => $crate::format::write_abbreviated(f, &self.0, prefix, n, n),
+					None if prefix => $crate::format::write_abbreviated(f, &self.0, prefix, 2, 2),
+					None => $crate::format::write_hex(f, &self.0, prefix, false),
 				}
-				$crate::core_::write!(f, "…")?;
-				for i in &self.0[$n_bytes - 2..$n_bytes] {
-					$crate::core_::write!(f, "{:02x}", i)?;
-				}
-				Ok(())
 			}
 		}
 
-		#[allow(unreachable_code)]
+		/// Lowercase hex, with a `0x` prefix for `{:#x}`.
 		impl $crate::core_::fmt::LowerHex for $name {
 			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
-				$($cut_prefix;
-					for i in &self.0 {
-						$crate::core_::write!(f, "{:02x}", i)?;
-					}
-					return Ok(());
-				)?
-
-				if f.alternate() {
-					$crate::core_::write!(f, "0x")?;
-				}
-				for i in &self.0[..] {
-					$crate::core_::write!(f, "{:02x}", i)?;
-				}
-				Ok(())
+				$crate::format::write_hex(f, &self.0, f.alternate(), false)
 			}
 		}
 
-		#[allow(unreachable_code)]
+		/// Uppercase hex, with a `0x` prefix for `{:#X}`.
 		impl $crate::core_::fmt::UpperHex for $name {
 			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
-				$($cut_prefix;
-					for i in &self.0 {
-						$crate::core_::write!(f, "{:0x}", i)?;
-					}
-					return Ok(());
-				)?
+				$crate::format::write_hex(f, &self.0, f.alternate(), true)
+			}
+		}
 
-				if f.alternate() {
-					$crate::core_::write!(f, "0X")?;
-				}
-				for i in &self.0[..] {
-					$crate::core_::write!(f, "{:02X}", i)?;
-				}
-				Ok(())
+		impl $crate::core_::str::FromStr for $name {
+			type Err = $crate::FromHexStrErr;
+
+			/// Creates a hash type instance from the given string.
+			///
+			/// # Note
+			///
+			/// The given input string is interpreted in big endian. It may start
+			/// with `0x`, use [`Self::from_str_strict`] to enforce the prefix
+			/// convention of the type.
+			///
+			/// # Errors
+			///
+			/// - When encountering invalid non hex-digits
+			/// - Upon empty string input or invalid input length in general
+			fn from_str(input: &str) -> $crate::core_::result::Result<$name, $crate::FromHexStrErr> {
+				let mut result = Self::zero();
+				$crate::parse::decode_hex_str(input, &mut result.0, None)?;
+				Ok(result)
+			}
+		}
+
+		impl $name {
+			/// Parses the hash like `FromStr`, but requires the `0x` prefix for
+			/// types formatted with one and rejects it for types formatted
+			/// without.
+			pub fn from_str_strict(input: &str) -> $crate::core_::result::Result<$name, $crate::FromHexStrErr> {
+				let mut result = Self::zero();
+				$crate::parse::decode_hex_str(input, &mut result.0, Some(Self::HEX_PREFIX))?;
+				Ok(result)
 			}
 		}
 
@@ -339,9 +452,14 @@ macro_rules! construct_fixed_hash {
 		impl_byteorder_for_fixed_hash!($name);
 		impl_rand_for_fixed_hash!($name);
 		impl_cmp_for_fixed_hash!($name);
-		impl_rustc_hex_for_fixed_hash!($name);
 		impl_quickcheck_for_fixed_hash!($name);
 		impl_arbitrary_for_fixed_hash!($name);
+		impl_zeroize_for_fixed_hash!($name);
+		impl_subtle_for_fixed_hash!($name);
+		impl_bytemuck_for_fixed_hash!($name);
+		impl_base58_for_fixed_hash!($name);
+		impl_base64_for_fixed_hash!($name);
+		impl_bech32_for_fixed_hash!($name);
 	}
 }
 
@@ -574,127 +692,315 @@ macro_rules! impl_cmp_for_fixed_hash {
 	};
 }
 
-// Implementation for disabled rustc-hex crate support.
+// Implementation for disabled quickcheck crate support.
 //
 // # Note
 //
 // Feature guarded macro definitions instead of feature guarded impl blocks
-// to work around the problems of introducing `rustc-hex` crate feature in
+// to work around the problems of introducing `quickcheck` crate feature in
 // a user crate.
-#[cfg(not(feature = "rustc-hex"))]
+#[cfg(not(feature = "quickcheck"))]
 #[macro_export]
 #[doc(hidden)]
-macro_rules! impl_rustc_hex_for_fixed_hash {
+macro_rules! impl_quickcheck_for_fixed_hash {
 	( $name:ident ) => {};
 }
 
-// Implementation for enabled rustc-hex crate support.
+// Implementation for enabled quickcheck crate support.
 //
 // # Note
 //
 // Feature guarded macro definitions instead of feature guarded impl blocks
-// to work around the problems of introducing `rustc-hex` crate feature in
+// to work around the problems of introducing `quickcheck` crate feature in
 // a user crate.
-#[cfg(feature = "rustc-hex")]
+#[cfg(feature = "quickcheck")]
 #[macro_export]
 #[doc(hidden)]
-macro_rules! impl_rustc_hex_for_fixed_hash {
+macro_rules! impl_quickcheck_for_fixed_hash {
 	( $name:ident ) => {
-		impl $crate::core_::str::FromStr for $name {
-			type Err = $crate::rustc_hex::FromHexError;
+		impl $crate::quickcheck::Arbitrary for $name {
+			fn arbitrary(g: &mut $crate::quickcheck::Gen) -> Self {
+				let res: [u8; Self::len_bytes()] = $crate::core_::array::from_fn(|_| u8::arbitrary(g));
+				Self::from(res)
+			}
+		}
+	};
+}
 
-			/// Creates a hash type instance from the given string.
-			///
-			/// # Note
-			///
-			/// The given input string is interpreted in big endian.
-			///
-			/// # Errors
-			///
-			/// - When encountering invalid non hex-digits
-			/// - Upon empty string input or invalid input length in general
-			fn from_str(input: &str) -> $crate::core_::result::Result<$name, $crate::rustc_hex::FromHexError> {
-				let input = input.strip_prefix("0x").unwrap_or(input);
-				let mut iter = $crate::rustc_hex::FromHexIter::new(input);
-				let mut result = Self::zero();
-				for byte in result.as_mut() {
-					*byte = iter.next().ok_or(Self::Err::InvalidHexLength)??;
-				}
-				if iter.next().is_some() {
-					return Err(Self::Err::InvalidHexLength)
+// When the `arbitrary` feature is disabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `arbitrary` crate feature in
+// a user crate.
+#[cfg(not(feature = "arbitrary"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_arbitrary_for_fixed_hash {
+	( $name:ident ) => {};
+}
+
+// When the `arbitrary` feature is enabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `arbitrary` crate feature in
+// a user crate.
+#[cfg(feature = "arbitrary")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_arbitrary_for_fixed_hash {
+	( $name:ident ) => {
+		impl $crate::arbitrary::Arbitrary<'_> for $name {
+			fn arbitrary(u: &mut $crate::arbitrary::Unstructured<'_>) -> $crate::arbitrary::Result<Self> {
+				let mut res = Self::zero();
+				u.fill_buffer(&mut res.0)?;
+				Ok(Self::from(res))
+			}
+		}
+	};
+}
+
+// When the `zeroize` feature is disabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `zeroize` crate feature in
+// a user crate.
+#[cfg(not(feature = "zeroize"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_zeroize_for_fixed_hash {
+	( $name:ident ) => {};
+}
+
+// When the `zeroize` feature is enabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `zeroize` crate feature in
+// a user crate.
+#[cfg(feature = "zeroize")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_zeroize_for_fixed_hash {
+	( $name:ident ) => {
+		impl $crate::zeroize::Zeroize for $name {
+			fn zeroize(&mut self) {
+				$crate::zeroize::Zeroize::zeroize(&mut self.0);
+			}
+		}
+	};
+}
+
+// When the `subtle` feature is disabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `subtle` crate feature in
+// a user crate.
+#[cfg(not(feature = "subtle"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_subtle_for_fixed_hash {
+	( $name:ident ) => {};
+}
+
+// When the `subtle` feature is enabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `subtle` crate feature in
+// a user crate.
+#[cfg(feature = "subtle")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_subtle_for_fixed_hash {
+	( $name:ident ) => {
+		impl $crate::subtle::ConstantTimeEq for $name {
+			fn ct_eq(&self, other: &Self) -> $crate::subtle::Choice {
+				$crate::subtle::ConstantTimeEq::ct_eq(self.as_bytes(), other.as_bytes())
+			}
+		}
+
+		impl $crate::subtle::ConditionallySelectable for $name {
+			fn conditional_select(a: &Self, b: &Self, choice: $crate::subtle::Choice) -> Self {
+				let mut ret = Self::zero();
+				for ((r, a), b) in ret.0.iter_mut().zip(a.0.iter()).zip(b.0.iter()) {
+					*r = $crate::subtle::ConditionallySelectable::conditional_select(a, b, choice);
 				}
+				ret
+			}
+		}
+	};
+}
+
+// When the `bytemuck` feature is disabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `bytemuck` crate feature in
+// a user crate.
+#[cfg(not(feature = "bytemuck"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_bytemuck_for_fixed_hash {
+	( $name:ident ) => {};
+}
+
+// When the `bytemuck` feature is enabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `bytemuck` crate feature in
+// a user crate.
+#[cfg(feature = "bytemuck")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_bytemuck_for_fixed_hash {
+	( $name:ident ) => {
+		// SAFETY: the type is a `#[repr(C)]` wrapper of a byte array, so it has
+		// no padding, alignment 1 and every bit pattern is valid.
+		unsafe impl $crate::bytemuck::Zeroable for $name {}
+		unsafe impl $crate::bytemuck::Pod for $name {}
+	};
+}
+
+// When the `bs58` feature is disabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `bs58` crate feature in
+// a user crate.
+#[cfg(not(feature = "bs58"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_base58_for_fixed_hash {
+	( $name:ident ) => {};
+}
+
+// When the `bs58` feature is enabled.
+//
+// # Note
+//
+// Feature guarded macro definitions instead of feature guarded impl blocks
+// to work around the problems of introducing `bs58` crate feature in
+// a user crate.
+#[cfg(feature = "bs58")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_base58_for_fixed_hash {
+	( $name:ident ) => {
+		impl $name {
+			/// Encodes the hash in base58 with the Bitcoin alphabet.
+			pub fn to_base58(&self) -> $crate::alloc_::string::String {
+				$crate::encoding::to_base58(&self.0)
+			}
+
+			/// Decodes a hash from base58 with the Bitcoin alphabet.
+			pub fn from_base58(input: &str) -> $crate::core_::result::Result<Self, $crate::FromEncodedStrErr> {
+				let mut result = Self::zero();
+				$crate::encoding::from_base58(input, &mut result.0)?;
 				Ok(result)
 			}
 		}
 	};
 }
 
-// Implementation for disabled quickcheck crate support.
+// When the `base64` feature is disabled.
 //
 // # Note
 //
 // Feature guarded macro definitions instead of feature guarded impl blocks
-// to work around the problems of introducing `quickcheck` crate feature in
+// to work around the problems of introducing `base64` crate feature in
 // a user crate.
-#[cfg(not(feature = "quickcheck"))]
+#[cfg(not(feature = "base64"))]
 #[macro_export]
 #[doc(hidden)]
-macro_rules! impl_quickcheck_for_fixed_hash {
+macro_rules! impl_base64_for_fixed_hash {
 	( $name:ident ) => {};
 }
 
-// Implementation for enabled quickcheck crate support.
+// When the `base64` feature is enabled.
 //
 // # Note
 //
 // Feature guarded macro definitions instead of feature guarded impl blocks
-// to work around the problems of introducing `quickcheck` crate feature in
+// to work around the problems of introducing `base64` crate feature in
 // a user crate.
-#[cfg(feature = "quickcheck")]
+#[cfg(feature = "base64")]
 #[macro_export]
 #[doc(hidden)]
-macro_rules! impl_quickcheck_for_fixed_hash {
+macro_rules! impl_base64_for_fixed_hash {
 	( $name:ident ) => {
-		impl $crate::quickcheck::Arbitrary for $name {
-			fn arbitrary(g: &mut $crate::quickcheck::Gen) -> Self {
-				let res: [u8; Self::len_bytes()] = $crate::core_::array::from_fn(|_| u8::arbitrary(g));
-				Self::from(res)
+		impl $name {
+			/// Encodes the hash in padded base64 with the standard alphabet.
+			pub fn to_base64(&self) -> $crate::alloc_::string::String {
+				$crate::encoding::to_base64(&self.0)
+			}
+
+			/// Decodes a hash from padded base64 with the standard alphabet.
+			pub fn from_base64(input: &str) -> $crate::core_::result::Result<Self, $crate::FromEncodedStrErr> {
+				let mut result = Self::zero();
+				$crate::encoding::from_base64(input, &mut result.0)?;
+				Ok(result)
 			}
 		}
 	};
 }
 
-// When the `arbitrary` feature is disabled.
+// When the `bech32` feature is disabled.
 //
 // # Note
 //
 // Feature guarded macro definitions instead of feature guarded impl blocks
-// to work around the problems of introducing `arbitrary` crate feature in
+// to work around the problems of introducing `bech32` crate feature in
 // a user crate.
-#[cfg(not(feature = "arbitrary"))]
+#[cfg(not(feature = "bech32"))]
 #[macro_export]
 #[doc(hidden)]
-macro_rules! impl_arbitrary_for_fixed_hash {
+macro_rules! impl_bech32_for_fixed_hash {
 	( $name:ident ) => {};
 }
 
-// When the `arbitrary` feature is enabled.
+// When the `bech32` feature is enabled.
 //
 // # Note
 //
 // Feature guarded macro definitions instead of feature guarded impl blocks
-// to work around the problems of introducing `arbitrary` crate feature in
+// to work around the problems of introducing `bech32` crate feature in
 // a user crate.
-#[cfg(feature = "arbitrary")]
+#[cfg(feature = "bech32")]
 #[macro_export]
 #[doc(hidden)]
-macro_rules! impl_arbitrary_for_fixed_hash {
+macro_rules! impl_bech32_for_fixed_hash {
 	( $name:ident ) => {
-		impl $crate::arbitrary::Arbitrary<'_> for $name {
-			fn arbitrary(u: &mut $crate::arbitrary::Unstructured<'_>) -> $crate::arbitrary::Result<Self> {
-				let mut res = Self::zero();
-				u.fill_buffer(&mut res.0)?;
-				Ok(Self::from(res))
+		impl $name {
+			/// Encodes the hash in bech32m with the human-readable part `hrp`,
+			/// which is checksummed along with the hash.
+			///
+			/// # Panics
+			///
+			/// If `hrp` is not 1 to 83 printable ASCII characters of one case.
+			pub fn to_bech32(&self, hrp: &str) -> $crate::alloc_::string::String {
+				$crate::encoding::to_bech32(hrp, &self.0)
+			}
+
+			/// Decodes a hash from bech32m, checking the checksum and that the
+			/// human-readable part is `hrp`.
+			pub fn from_bech32(input: &str, hrp: &str) -> $crate::core_::result::Result<Self, $crate::FromEncodedStrErr> {
+				let mut result = Self::zero();
+				$crate::encoding::from_bech32(hrp, input, &mut result.0)?;
+				Ok(result)
 			}
 		}
 	};
@@ -50,8 +50,35 @@ pub use quickcheck;
 #[doc(hidden)]
 pub use arbitrary;
 
+#[cfg(feature = "zeroize")]
+#[doc(hidden)]
+pub use zeroize;
+
+#[cfg(feature = "subtle")]
+#[doc(hidden)]
+pub use subtle;
+
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+pub use bytemuck;
+
 #[macro_use]
 mod hash;
+#[macro_use]
+mod literal;
+#[doc(hidden)]
+pub mod format;
+#[doc(hidden)]
+pub mod parse;
+#[doc(hidden)]
+pub mod encoding;
+
+#[doc(hidden)]
+pub use literal::decode_hex;
+
+pub use format::{Abbreviated, Checksummed};
+pub use encoding::FromEncodedStrErr;
+pub use parse::FromHexStrErr;
 
 #[cfg(test)]
 mod tests;
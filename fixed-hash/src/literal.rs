@@ -0,0 +1,79 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// Decodes a hex string, with or without a `0x` prefix, into `N` bytes.
+///
+/// Panics on a wrong length or a non-hex character. Used by [`hash!`] in a
+/// constant, where that panic is a compile error.
+#[doc(hidden)]
+pub const fn decode_hex<const N: usize>(s: &str) -> [u8; N] {
+	let mut digits = s.as_bytes();
+	if let [b'0', b'x', rest @ ..] = digits {
+		digits = rest;
+	}
+	if digits.len() != 2 * N {
+		panic!("hex literal has the wrong length for this hash type");
+	}
+	let mut bytes = [0u8; N];
+	let mut i = 0;
+	while i < N {
+		bytes[i] = (hex_digit(digits[2 * i]) << 4) | hex_digit(digits[2 * i + 1]);
+		i += 1;
+	}
+	bytes
+}
+
+const fn hex_digit(c: u8) -> u8 {
+	match c {
+		b'0'..=b'9' => c - b'0',
+		b'a'..=b'f' => c - b'a' + 10,
+		b'A'..=b'F' => c - b'A' + 10,
+		_ => panic!("hex literal contains a non-hex character"),
+	}
+}
+
+/// Creates a constant of a fixed hash type from a hex string literal.
+///
+/// The string may start with `0x` and must have exactly two hex digits per
+/// byte of the hash. It is decoded at compile time, so the macro can be used
+/// in `const` and `static` items, and a malformed string fails to compile.
+///
+/// # Example
+///
+/// ```
+/// use fixed_hash::{construct_fixed_hash, hash};
+/// construct_fixed_hash!{ pub struct H32(4); }
+///
+/// const MAGIC: H32 = hash!(H32, "0xdeadbeef");
+/// assert_eq!(MAGIC, H32([0xde, 0xad, 0xbe, 0xef]));
+/// ```
+///
+/// A string of the wrong length does not compile:
+///
+/// ```compile_fail
+/// use fixed_hash::{construct_fixed_hash, hash};
+/// construct_fixed_hash!{ pub struct H32(4); }
+///
+/// let short = hash!(H32, "deadbe");
+/// ```
+///
+/// Neither does one with a character that is not a hex digit:
+///
+/// ```compile_fail
+/// use fixed_hash::{construct_fixed_hash, hash};
+/// construct_fixed_hash!{ pub struct H32(4); }
+///
+/// let bad = hash!(H32, "deadbeeg");
+/// ```
+#[macro_export]
+macro_rules! hash {
+	($name:path, $hex:expr) => {{
+		const VALUE: $name = $name($crate::decode_hex($hex));
+		VALUE
+	}};
+}
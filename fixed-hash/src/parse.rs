@@ -0,0 +1,88 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+
+/// Error parsing a fixed hash from a hex string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromHexStrErr {
+	/// The number of hex digits, not counting a `0x` prefix, is wrong.
+	InvalidLength {
+		/// Hex digits the hash type needs, two per byte.
+		expected: usize,
+		/// Characters found after the prefix.
+		actual: usize,
+	},
+	/// A character is not a hex digit.
+	InvalidCharacter {
+		/// The offending character.
+		character: char,
+		/// Its byte index in the input, counting the prefix.
+		index: usize,
+	},
+	/// The `0x` prefix is required but missing.
+	MissingPrefix,
+	/// The `0x` prefix is not allowed for this type but present.
+	UnexpectedPrefix,
+}
+
+impl fmt::Display for FromHexStrErr {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			FromHexStrErr::InvalidLength { expected, actual } => {
+				write!(f, "invalid length: expected {} hex digits, got {}", expected, actual)
+			},
+			FromHexStrErr::InvalidCharacter { character, index } => {
+				write!(f, "invalid character {:?} at index {}", character, index)
+			},
+			FromHexStrErr::MissingPrefix => f.write_str("missing 0x prefix"),
+			FromHexStrErr::UnexpectedPrefix => f.write_str("unexpected 0x prefix"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromHexStrErr {}
+
+#[cfg(feature = "rustc-hex")]
+impl From<FromHexStrErr> for rustc_hex::FromHexError {
+	fn from(err: FromHexStrErr) -> Self {
+		match err {
+			FromHexStrErr::InvalidCharacter { character, index } => rustc_hex::FromHexError::InvalidHexCharacter(character, index),
+			_ => rustc_hex::FromHexError::InvalidHexLength,
+		}
+	}
+}
+
+/// Decodes `input` into `bytes`. The `0x` prefix is optional if `prefix` is
+/// `None`, and otherwise required or rejected.
+#[doc(hidden)]
+pub fn decode_hex_str(input: &str, bytes: &mut [u8], prefix: Option<bool>) -> Result<(), FromHexStrErr> {
+	let (digits, offset) = match (input.strip_prefix("0x"), prefix) {
+		(Some(_), Some(false)) => return Err(FromHexStrErr::UnexpectedPrefix),
+		(None, Some(true)) => return Err(FromHexStrErr::MissingPrefix),
+		(Some(digits), _) => (digits, 2),
+		(None, _) => (input, 0),
+	};
+	let expected = bytes.len() * 2;
+	let mut actual = 0;
+	for (index, character) in digits.char_indices() {
+		let nibble = match character.to_digit(16) {
+			Some(nibble) => nibble as u8,
+			None => return Err(FromHexStrErr::InvalidCharacter { character, index: index + offset }),
+		};
+		if let Some(byte) = bytes.get_mut(actual / 2) {
+			*byte = if actual % 2 == 0 { nibble << 4 } else { *byte | nibble };
+		}
+		actual += 1;
+	}
+	if actual != expected {
+		return Err(FromHexStrErr::InvalidLength { expected, actual })
+	}
+	Ok(())
+}
@@ -155,6 +155,228 @@ mod is_zero {
 	}
 }
 
+mod distance {
+	use super::*;
+	use crate::core_::cmp::Ordering;
+
+	#[test]
+	fn xor_distance() {
+		let a = H32([0xff, 0x00, 0x0f, 0x01]);
+		let b = H32([0x0f, 0x00, 0xff, 0x01]);
+		assert_eq!(a.xor_distance(&b), H32([0xf0, 0x00, 0xf0, 0x00]));
+		assert_eq!(a.xor_distance(&b), b.xor_distance(&a));
+		assert!(a.xor_distance(&a).is_zero());
+	}
+
+	#[test]
+	fn leading_zeros() {
+		assert_eq!(H32::zero().leading_zeros(), 32);
+		assert_eq!(H32::repeat_byte(0xff).leading_zeros(), 0);
+		assert_eq!(H32([0, 0, 0x10, 0xff]).leading_zeros(), 19);
+		assert_eq!(H256::from_low_u64_be(1).leading_zeros(), 255);
+	}
+
+	#[test]
+	fn common_prefix_and_log2() {
+		let a = H32([0b1010_0000, 0, 0, 0]);
+		let b = H32([0b1011_0000, 0, 0, 0]);
+		assert_eq!(a.common_prefix_len(&b), 3);
+		assert_eq!(a.log2_distance(&b), Some(28));
+		assert_eq!(a.common_prefix_len(&a), 32);
+		assert_eq!(a.log2_distance(&a), None);
+		assert_eq!(H32::zero().log2_distance(&H32([0, 0, 0, 1])), Some(0));
+		assert_eq!(H256::zero().log2_distance(&H256::repeat_byte(0x80)), Some(255));
+	}
+
+	#[test]
+	fn cmp_distance() {
+		let target = H32([0x10, 0, 0, 0]);
+		let near = H32([0x11, 0xff, 0xff, 0xff]);
+		let far = H32([0x00, 0, 0, 0]);
+		assert_eq!(target.cmp_distance(&near, &far), Ordering::Less);
+		assert_eq!(target.cmp_distance(&far, &near), Ordering::Greater);
+		assert_eq!(target.cmp_distance(&near, &near), Ordering::Equal);
+
+		let mut peers = vec![far, target, near];
+		peers.sort_by(|a, b| target.cmp_distance(a, b));
+		assert_eq!(peers, vec![target, near, far]);
+	}
+
+	#[test]
+	fn bits() {
+		let mut hash = H32::zero();
+		hash.set_bit(0, true);
+		hash.set_bit(12, true);
+		hash.set_bit(31, true);
+		assert_eq!(hash, H32([0x80, 0x08, 0x00, 0x01]));
+		assert!(hash.bit(0) && hash.bit(12) && hash.bit(31));
+		assert!(!hash.bit(1) && !hash.bit(30));
+		assert_eq!(hash.leading_zeros(), 0);
+		hash.set_bit(0, false);
+		assert_eq!(hash.leading_zeros(), 12);
+	}
+
+	#[test]
+	#[should_panic]
+	fn bit_out_of_range() {
+		H32::zero().bit(32);
+	}
+}
+
+mod ct_eq_bool {
+	use super::*;
+
+	#[test]
+	fn matches_eq() {
+		let a = H256::repeat_byte(0x5a);
+		let mut b = a;
+		assert!(a.ct_eq_bool(&b));
+		b.0[31] ^= 1;
+		assert!(!a.ct_eq_bool(&b));
+		b = a;
+		b.0[0] ^= 0x80;
+		assert!(!a.ct_eq_bool(&b));
+		assert!(H32::zero().ct_eq_bool(&H32::zero()));
+	}
+}
+
+#[cfg(feature = "zeroize")]
+mod zeroize {
+	use super::*;
+	use ::zeroize::Zeroize;
+
+	#[test]
+	fn clears_bytes() {
+		let mut h = H256::repeat_byte(0xff);
+		h.zeroize();
+		assert!(h.is_zero());
+	}
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck {
+	use super::*;
+
+	#[test]
+	fn cast_slice() {
+		let bytes: Vec<u8> = (0..64).collect();
+		let hashes: &[H256] = ::bytemuck::cast_slice(&bytes);
+		assert_eq!(hashes.len(), 2);
+		assert_eq!(hashes[0].as_bytes(), &bytes[..32]);
+		assert_eq!(hashes[1].as_bytes(), &bytes[32..]);
+		assert_eq!(::bytemuck::cast_slice::<H256, u8>(hashes), &bytes[..]);
+		assert_eq!(<H160 as ::bytemuck::Zeroable>::zeroed(), H160::zero());
+	}
+
+	#[test]
+	fn wrong_length() {
+		let bytes = [0u8; 33];
+		assert!(::bytemuck::try_cast_slice::<u8, H256>(&bytes).is_err());
+	}
+}
+
+#[cfg(feature = "bs58")]
+mod base58 {
+	use super::*;
+	use crate::FromEncodedStrErr;
+
+	#[test]
+	fn round_trip() {
+		assert_eq!(H32([0, 0, 0, 1]).to_base58(), "1112");
+		assert_eq!(H32::from_base58("1112"), Ok(H32([0, 0, 0, 1])));
+		let hash = H256::repeat_byte(0xab);
+		assert_eq!(H256::from_base58(&hash.to_base58()), Ok(hash));
+	}
+
+	#[test]
+	fn errors() {
+		assert_eq!(H32::from_base58("0OIl"), Err(FromEncodedStrErr::InvalidEncoding));
+		assert_eq!(H32::from_base58("112"), Err(FromEncodedStrErr::InvalidLength { expected: 4, actual: 3 }));
+	}
+}
+
+#[cfg(feature = "base64")]
+mod base64 {
+	use super::*;
+	use crate::FromEncodedStrErr;
+
+	#[test]
+	fn round_trip() {
+		assert_eq!(H32([0xde, 0xad, 0xbe, 0xef]).to_base64(), "3q2+7w==");
+		assert_eq!(H32::from_base64("3q2+7w=="), Ok(H32([0xde, 0xad, 0xbe, 0xef])));
+		let hash = H256::repeat_byte(0xab);
+		assert_eq!(H256::from_base64(&hash.to_base64()), Ok(hash));
+	}
+
+	#[test]
+	fn errors() {
+		assert_eq!(H32::from_base64("3q2+7w"), Err(FromEncodedStrErr::InvalidEncoding));
+		assert_eq!(H32::from_base64("3q2+"), Err(FromEncodedStrErr::InvalidLength { expected: 4, actual: 3 }));
+	}
+}
+
+#[cfg(feature = "bech32")]
+mod bech32 {
+	use super::*;
+	use crate::FromEncodedStrErr;
+
+	#[test]
+	fn round_trip() {
+		let hash = H32([0xde, 0xad, 0xbe, 0xef]);
+		assert_eq!(hash.to_bech32("core"), "core1m6kmamcetk756");
+		assert_eq!(H32::from_bech32("core1m6kmamcetk756", "core"), Ok(hash));
+		assert_eq!(H32::from_bech32("CORE1M6KMAMCETK756", "core"), Ok(hash));
+		assert_eq!(
+			H256::zero().to_bech32("core"),
+			"core1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqql8xsa4"
+		);
+		// longer than the 90 characters of BIP-173 addresses
+		construct_fixed_hash! { pub struct H1024(128); }
+		let hash = H1024::repeat_byte(0x5a);
+		assert_eq!(H1024::from_bech32(&hash.to_bech32("pk"), "pk"), Ok(hash));
+	}
+
+	#[test]
+	fn errors() {
+		assert_eq!(H32::from_bech32("core1m6kmamcetk757", "core"), Err(FromEncodedStrErr::InvalidChecksum));
+		assert_eq!(H32::from_bech32("core1m6kmamcetk756", "cora"), Err(FromEncodedStrErr::UnexpectedPrefix));
+		assert_eq!(H32::from_bech32("m6kmamcetk756", "core"), Err(FromEncodedStrErr::InvalidEncoding));
+		assert_eq!(
+			H64::from_bech32("core1m6kmamcetk756", "core"),
+			Err(FromEncodedStrErr::InvalidLength { expected: 8, actual: 4 })
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "invalid bech32 human-readable part")]
+	fn bad_hrp() {
+		H32::zero().to_bech32("");
+	}
+}
+
+#[cfg(feature = "subtle")]
+mod subtle {
+	use super::*;
+	use ::subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+	#[test]
+	fn constant_time_eq() {
+		let a = H160::repeat_byte(1);
+		// method syntax resolves to the trait, not to an inherent method
+		let eq: Choice = a.ct_eq(&a);
+		assert!(bool::from(eq));
+		assert!(!bool::from(ConstantTimeEq::ct_eq(&a, &H160::repeat_byte(2))));
+	}
+
+	#[test]
+	fn conditional_select() {
+		let a = H64::repeat_byte(1);
+		let b = H64::repeat_byte(2);
+		assert_eq!(H64::conditional_select(&a, &b, Choice::from(0)), a);
+		assert_eq!(H64::conditional_select(&a, &b, Choice::from(1)), b);
+	}
+}
+
 #[cfg(feature = "byteorder")]
 mod to_low_u64 {
 	use super::*;
@@ -249,7 +471,6 @@ mod rand {
 	}
 }
 
-#[cfg(feature = "rustc-hex")]
 mod from_str {
 	use super::*;
 
@@ -280,6 +501,83 @@ mod from_str {
 		use crate::core_::str::FromStr;
 		assert!(H64::from_str("0123456789ABCDEF0").is_err())
 	}
+
+	#[test]
+	fn errors() {
+		use crate::FromHexStrErr;
+
+		assert_eq!("".parse::<H64>(), Err(FromHexStrErr::InvalidLength { expected: 16, actual: 0 }));
+		assert_eq!("0x".parse::<H64>(), Err(FromHexStrErr::InvalidLength { expected: 16, actual: 0 }));
+		assert_eq!("0x0123".parse::<H64>(), Err(FromHexStrErr::InvalidLength { expected: 16, actual: 4 }));
+		assert_eq!(
+			"0123456789ABCDEF0".parse::<H64>(),
+			Err(FromHexStrErr::InvalidLength { expected: 16, actual: 17 })
+		);
+		assert_eq!(
+			"0x01234g".parse::<H64>(),
+			Err(FromHexStrErr::InvalidCharacter { character: 'g', index: 7 })
+		);
+		assert_eq!("0xü1".parse::<H64>(), Err(FromHexStrErr::InvalidCharacter { character: 'ü', index: 2 }));
+		assert_eq!("0X00".parse::<H64>(), Err(FromHexStrErr::InvalidCharacter { character: 'X', index: 1 }));
+		assert_eq!(
+			"0x01234".parse::<H64>().unwrap_err().to_string(),
+			"invalid length: expected 16 hex digits, got 5"
+		);
+		assert_eq!("-1".parse::<H64>().unwrap_err().to_string(), "invalid character '-' at index 0");
+	}
+
+	#[test]
+	fn strict() {
+		use crate::FromHexStrErr;
+		construct_fixed_hash! { pub struct Bare(2, true); }
+
+		assert_eq!(H32::from_str_strict("0x0a0b0c0d"), Ok(H32([10, 11, 12, 13])));
+		assert_eq!(H32::from_str_strict("0a0b0c0d"), Err(FromHexStrErr::MissingPrefix));
+		assert_eq!(Bare::from_str_strict("0a0b"), Ok(Bare([10, 11])));
+		assert_eq!(Bare::from_str_strict("0x0a0b"), Err(FromHexStrErr::UnexpectedPrefix));
+		assert_eq!("0x0a0b".parse::<Bare>(), Ok(Bare([10, 11])));
+		assert_eq!(FromHexStrErr::MissingPrefix.to_string(), "missing 0x prefix");
+	}
+
+	#[cfg(feature = "rustc-hex")]
+	#[test]
+	fn into_rustc_hex() {
+		use crate::{rustc_hex::FromHexError, FromHexStrErr};
+
+		assert!(matches!(
+			FromHexError::from(FromHexStrErr::InvalidCharacter { character: 'z', index: 3 }),
+			FromHexError::InvalidHexCharacter('z', 3)
+		));
+		assert!(matches!(FromHexError::from(FromHexStrErr::MissingPrefix), FromHexError::InvalidHexLength));
+	}
+}
+
+mod hash_literal {
+	use super::*;
+
+	const ONE: H32 = hash!(H32, "0x00000001");
+
+	#[test]
+	fn decodes() {
+		assert_eq!(ONE, H32([0, 0, 0, 1]));
+		assert_eq!(hash!(H32, "deadBEEF"), H32([0xde, 0xad, 0xbe, 0xef]));
+		assert_eq!(
+			hash!(H256, "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"),
+			"c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470".parse::<H256>().unwrap()
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "wrong length")]
+	fn wrong_length() {
+		crate::decode_hex::<4>("0x000001");
+	}
+
+	#[test]
+	#[should_panic(expected = "non-hex character")]
+	fn bad_character() {
+		crate::decode_hex::<4>("0000000x");
+	}
 }
 
 #[test]
@@ -349,6 +647,112 @@ fn display_and_debug() {
 	test_for(0x1000, "0000000000001000", "0000…1000");
 }
 
+mod format {
+	use super::*;
+	use tiny_keccak::{Hasher, Keccak};
+
+	construct_fixed_hash! { pub struct Bare(4, true); }
+
+	fn keccak(data: &[u8]) -> [u8; 32] {
+		let mut out = [0u8; 32];
+		let mut keccak = Keccak::v256();
+		keccak.update(data);
+		keccak.finalize(&mut out);
+		out
+	}
+
+	#[test]
+	fn upper_hex() {
+		let hash = H32([0x0a, 0x01, 0xbc, 0x00]);
+		assert_eq!(format!("{:X}", hash), "0A01BC00");
+		assert_eq!(format!("{:#X}", hash), "0x0A01BC00");
+		let bare = Bare([0x0a, 0x01, 0xbc, 0x00]);
+		assert_eq!(format!("{:X}", bare), "0A01BC00");
+		assert_eq!(format!("{:#X}", bare), "0x0A01BC00");
+	}
+
+	#[test]
+	fn prefix_less() {
+		let bare = Bare([0x0a, 0x01, 0xbc, 0x00]);
+		assert_eq!(format!("{}", bare), "0a01bc00");
+		assert_eq!(format!("{:?}", bare), "0a01bc00");
+		assert_eq!(format!("{:x}", bare), "0a01bc00");
+		assert_eq!(format!("{:#x}", bare), "0x0a01bc00");
+		assert_eq!(format!("{:.1}", bare), "0a…00");
+	}
+
+	#[test]
+	fn width_and_fill() {
+		let hash = H32([0x0a, 0x01, 0xbc, 0x00]);
+		assert_eq!(format!("{:12x}", hash), "    0a01bc00");
+		assert_eq!(format!("{:<12x}", hash), "0a01bc00    ");
+		assert_eq!(format!("{:*^13x}", hash), "**0a01bc00***");
+		assert_eq!(format!("{:#012x}", hash), "0x000a01bc00");
+		assert_eq!(format!("{:#14X}", hash), "    0x0A01BC00");
+		assert_eq!(format!("{:4x}", hash), "0a01bc00");
+		assert_eq!(format!("{:>12?}", hash), "  0x0a01bc00");
+		assert_eq!(format!("{:-<12}", H64::from_low_u64_be(1)), "0x0000…0001-");
+	}
+
+	#[test]
+	fn abbreviated() {
+		let hash = H64::from_low_u64_be(0x0102_0304_0506_0708);
+		assert_eq!(format!("{}", hash), "0x0102…0708");
+		assert_eq!(format!("{:.1}", hash), "0x01…08");
+		assert_eq!(format!("{:.3}", hash), "0x010203…060708");
+		assert_eq!(format!("{:.4}", hash), "0x0102030405060708");
+		assert_eq!(format!("{:.0}", hash), "0x…");
+		assert_eq!(hash.abbreviated(3, 1).to_string(), "0x010203…08");
+		assert_eq!(hash.abbreviated(0, 8).to_string(), "0x0102030405060708");
+		assert_eq!(Bare([1, 2, 3, 4]).abbreviated(1, 0).to_string(), "01…");
+		assert_eq!(format!("{:>10}", hash.abbreviated(1, 1)), "   0x01…08");
+	}
+
+	#[test]
+	fn checksummed() {
+		// EIP-55 test vectors
+		for s in &[
+			"5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+			"fB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+			"dbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+			"D1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+		] {
+			let hash: H160 = s.parse().unwrap();
+			let checksummed = hash.checksummed(keccak);
+			assert_eq!(checksummed.to_string(), *s);
+			assert_eq!(format!("{:#}", checksummed), format!("0x{}", s));
+			assert!(checksummed.matches(s));
+			assert!(checksummed.matches(&format!("0x{}", s)));
+			assert!(!checksummed.matches(&s.to_lowercase()));
+			assert!(!checksummed.matches(&s[2..]));
+		}
+	}
+
+	#[test]
+	fn from_str_round_trip() {
+		let hashes = [H64::zero(), H64::repeat_byte(0xff), H64::from_low_u64_be(0x0a0b_0c0d), H64::from_low_u64_be(0x1)];
+		for hash in &hashes {
+			for s in &[
+				format!("{:x}", hash),
+				format!("{:#x}", hash),
+				format!("{:X}", hash),
+				format!("{:#X}", hash),
+				format!("{:?}", hash),
+				format!("{}", hash.checksummed(keccak)),
+				format!("{:#}", hash.checksummed(keccak)),
+			] {
+				assert_eq!(s.parse::<H64>().unwrap(), *hash, "{}", s);
+			}
+		}
+		for bytes in &[[0u8; 4], [0x0f, 0xf0, 0x01, 0x10]] {
+			let bare = Bare(*bytes);
+			for s in &[format!("{}", bare), format!("{:?}", bare), format!("{:#x}", bare), format!("{:X}", bare)] {
+				assert_eq!(s.parse::<Bare>().unwrap(), bare, "{}", s);
+			}
+		}
+	}
+}
+
 mod ops {
 	use super::*;
 
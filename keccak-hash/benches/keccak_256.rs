@@ -6,10 +6,16 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use keccak_hash::keccak;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use keccak_hash::{keccak, keccak_batch};
 
-criterion_group!(keccak_256, keccak_256_with_empty_input, keccak_256_with_typical_input, keccak_256_with_large_input,);
+criterion_group!(
+	keccak_256,
+	keccak_256_with_empty_input,
+	keccak_256_with_typical_input,
+	keccak_256_with_large_input,
+	keccak_256_batch,
+);
 criterion_main!(keccak_256);
 
 pub fn keccak_256_with_empty_input(c: &mut Criterion) {
@@ -53,3 +59,24 @@ pub fn keccak_256_with_large_input(c: &mut Criterion) {
 		})
 	});
 }
+
+pub fn keccak_256_batch(c: &mut Criterion) {
+	// transaction-sized inputs, 100 to 300 bytes
+	let data: Vec<Vec<u8>> = (0..4096u32).map(|i| i.to_be_bytes().repeat(25 + i as usize % 50)).collect();
+	let mut group = c.benchmark_group("keccak_256_batch");
+	for len in [16, 256, 4096] {
+		let inputs = &data[..len];
+		group.bench_with_input(BenchmarkId::new("serial", len), inputs, |b, inputs| {
+			b.iter(|| {
+				let _out: Vec<_> = black_box(inputs).iter().map(keccak).collect();
+			})
+		});
+		group.bench_with_input(BenchmarkId::new("batch", len), inputs, |b, inputs| {
+			b.iter(|| {
+				let _out = keccak_batch(black_box(inputs));
+			})
+		});
+	}
+
+	group.finish();
+}
@@ -0,0 +1,263 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Incremental hashers.
+
+use crate::H256;
+use core::fmt;
+use tiny_keccak::{Hasher, Keccak, Sha3};
+
+macro_rules! construct_hasher {
+	($(#[$attr:meta])* $name:ident, $state:ident, $hash:expr) => {
+		$(#[$attr])*
+		#[derive(Clone)]
+		pub struct $name($state);
+
+		impl $name {
+			/// Creates a hasher for the empty input.
+			pub fn new() -> Self {
+				$name($state::v256())
+			}
+
+			/// Feeds `data` to the hasher.
+			pub fn update<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+				self.0.update(data.as_ref());
+				self
+			}
+
+			/// The hash of everything fed so far. The hasher is left as it is,
+			/// so more data can still be fed.
+			pub fn finalize(&self) -> H256 {
+				let mut output = [0u8; 32];
+				self.0.clone().finalize(&mut output);
+				H256(output)
+			}
+
+			/// Starts over with the empty input.
+			pub fn reset(&mut self) {
+				self.0 = $state::v256();
+			}
+		}
+
+		impl Default for $name {
+			fn default() -> Self {
+				$name::new()
+			}
+		}
+
+		impl fmt::Debug for $name {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.debug_struct(stringify!($name)).finish_non_exhaustive()
+			}
+		}
+
+		#[cfg(feature = "std")]
+		impl std::io::Write for $name {
+			fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+				self.0.update(buf);
+				Ok(buf.len())
+			}
+
+			fn flush(&mut self) -> std::io::Result<()> {
+				Ok(())
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl digest::HashMarker for $name {}
+
+		#[cfg(feature = "digest")]
+		impl digest::OutputSizeUser for $name {
+			type OutputSize = digest::consts::U32;
+		}
+
+		#[cfg(feature = "digest")]
+		impl digest::Update for $name {
+			fn update(&mut self, data: &[u8]) {
+				self.0.update(data);
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl digest::FixedOutput for $name {
+			fn finalize_into(self, out: &mut digest::Output<Self>) {
+				self.0.finalize(out);
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl digest::FixedOutputReset for $name {
+			fn finalize_into_reset(&mut self, out: &mut digest::Output<Self>) {
+				core::mem::replace(&mut self.0, $state::v256()).finalize(out);
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl digest::Reset for $name {
+			fn reset(&mut self) {
+				$name::reset(self);
+			}
+		}
+
+		#[cfg(feature = "hash-db")]
+		impl hash_db::Hasher for $name {
+			type Out = H256;
+			type StdHasher = PlainHasher;
+			const LENGTH: usize = 32;
+
+			fn hash(x: &[u8]) -> H256 {
+				$hash(x)
+			}
+		}
+	};
+}
+
+construct_hasher! {
+	/// Incremental Keccak-256 hasher.
+	///
+	/// ```
+	/// use keccak_hash::{keccak, KeccakHasher};
+	///
+	/// let hash = KeccakHasher::new().update(b"hello ").update(b"world").finalize();
+	/// assert_eq!(hash, keccak(b"hello world"));
+	/// ```
+	KeccakHasher, Keccak, crate::keccak
+}
+
+construct_hasher! {
+	/// Incremental SHA3-256 hasher.
+	///
+	/// ```
+	/// use keccak_hash::{sha3, Sha3Hasher};
+	///
+	/// let mut hasher = Sha3Hasher::new();
+	/// hasher.update(b"hello ");
+	/// let fork = hasher.clone();
+	/// assert_eq!(hasher.update(b"world").finalize(), sha3(b"hello world"));
+	/// assert_eq!(fork.finalize(), sha3(b"hello "));
+	/// ```
+	Sha3Hasher, Sha3, crate::sha3
+}
+
+/// A `std::hash::Hasher` for keys that are already hashes, such as `H256`.
+///
+/// It folds the written bytes into a `u64` instead of hashing them again, so
+/// it is only fit for uniformly distributed keys.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PlainHasher {
+	prefix: u64,
+}
+
+impl core::hash::Hasher for PlainHasher {
+	#[inline]
+	fn finish(&self) -> u64 {
+		self.prefix
+	}
+
+	#[inline]
+	fn write(&mut self, bytes: &[u8]) {
+		for chunk in bytes.chunks(8) {
+			let mut word = [0u8; 8];
+			word[..chunk.len()].copy_from_slice(chunk);
+			self.prefix ^= u64::from_le_bytes(word);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{keccak, sha3, KECCAK_EMPTY, SHA3_EMPTY};
+	use core::hash::{Hash, Hasher as _};
+
+	#[test]
+	fn incremental() {
+		let mut hasher = KeccakHasher::new();
+		assert_eq!(hasher.finalize(), KECCAK_EMPTY);
+		hasher.update(b"some").update(b"thing");
+		assert_eq!(hasher.finalize(), keccak(b"something"));
+		hasher.reset();
+		assert_eq!(hasher.finalize(), KECCAK_EMPTY);
+
+		let mut hasher = Sha3Hasher::default();
+		assert_eq!(hasher.finalize(), SHA3_EMPTY);
+		assert_eq!(hasher.update(b"something").finalize(), sha3(b"something"));
+	}
+
+	#[test]
+	fn fork() {
+		let mut hasher = Sha3Hasher::new();
+		hasher.update(b"some");
+		let mut fork = hasher.clone();
+		fork.update(b"one");
+		hasher.update(b"thing");
+		assert_eq!(hasher.finalize(), sha3(b"something"));
+		assert_eq!(fork.finalize(), sha3(b"someone"));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn write() {
+		use std::io::Write;
+
+		let mut hasher = KeccakHasher::new();
+		hasher.write_all(b"hello ").unwrap();
+		hasher.write_all(b"world").unwrap();
+		assert_eq!(hasher.finalize(), keccak(b"hello world"));
+
+		let mut hasher = Sha3Hasher::new();
+		std::io::copy(&mut &b"something"[..], &mut hasher).unwrap();
+		assert_eq!(hasher.finalize(), sha3(b"something"));
+	}
+
+	#[cfg(feature = "digest")]
+	#[test]
+	fn digest() {
+		use digest::Digest;
+
+		assert_eq!(&Sha3Hasher::digest(b"abc")[..], sha3(b"abc").as_bytes());
+		let mut hasher = <KeccakHasher as Digest>::new();
+		Digest::update(&mut hasher, b"abc");
+		assert_eq!(&hasher.finalize_reset()[..], keccak(b"abc").as_bytes());
+		assert_eq!(&Digest::finalize(hasher)[..], KECCAK_EMPTY.as_bytes());
+	}
+
+	#[cfg(feature = "hash-db")]
+	#[test]
+	fn hash_db() {
+		assert_eq!(<KeccakHasher as hash_db::Hasher>::hash(b"abc"), keccak(b"abc"));
+		assert_eq!(<Sha3Hasher as hash_db::Hasher>::hash(b"abc"), sha3(b"abc"));
+		assert_eq!(<Sha3Hasher as hash_db::Hasher>::LENGTH, 32);
+	}
+
+	#[test]
+	fn plain_hasher() {
+		let mut bytes = [0u8; 32];
+		bytes[0] = 1;
+		bytes[9] = 2;
+		let mut hasher = PlainHasher::default();
+		H256(bytes).hash(&mut hasher);
+		assert_eq!(hasher.finish(), 0x0201);
+
+		let mut hasher = PlainHasher::default();
+		hasher.write(&[1, 2, 3]);
+		assert_eq!(hasher.finish(), 0x030201);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn plain_hasher_map() {
+		use std::{collections::HashMap, hash::BuildHasherDefault};
+
+		let mut map = HashMap::<H256, u32, BuildHasherDefault<PlainHasher>>::default();
+		map.insert(keccak(b"a"), 1);
+		map.insert(keccak(b"b"), 2);
+		assert_eq!(map.get(&keccak(b"a")), Some(&1));
+		assert_eq!(map.get(&keccak(b"c")), None);
+	}
+}
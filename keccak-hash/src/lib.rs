@@ -6,13 +6,32 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+//! Keccak and NIST SHA3 hashes.
+//!
+//! The `keccak*` functions use the original Keccak padding, as Ethereum does,
+//! and the `sha3*` functions the padding of the final NIST standard, as Core
+//! does. [`Algorithm`] picks one of the two per call, and [`hash`] uses
+//! [`Algorithm::DEFAULT`], which is SHA3 with the `sha3-default` feature and
+//! Keccak otherwise. [`KeccakHasher`] and [`Sha3Hasher`] hash incrementally,
+//! and [`MerkleTree`] builds binary Merkle trees and inclusion proofs.
+
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+mod hasher;
+mod merkle;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::io;
 
+pub use hasher::{KeccakHasher, PlainHasher, Sha3Hasher};
+pub use merkle::{MerkleMultiProof, MerkleProof, MerkleTree};
 pub use primitive_types::H256;
-use tiny_keccak::{Hasher, Keccak};
+use tiny_keccak::{Hasher, Keccak, Sha3};
 
 /// Get the KECCAK (i.e. Keccak) hash of the empty bytes string.
 pub const KECCAK_EMPTY: H256 = H256([
@@ -32,6 +51,126 @@ pub const KECCAK_EMPTY_LIST_RLP: H256 = H256([
 	0x1b, 0x94, 0x8a, 0x74, 0x13, 0xf0, 0xa1, 0x42, 0xfd, 0x40, 0xd4, 0x93, 0x47,
 ]);
 
+/// Get the SHA3-256 hash of the empty bytes string.
+pub const SHA3_EMPTY: H256 = H256([
+	0xa7, 0xff, 0xc6, 0xf8, 0xbf, 0x1e, 0xd7, 0x66, 0x51, 0xc1, 0x47, 0x56, 0xa0, 0x61, 0xd6, 0x62, 0xf5, 0x80, 0xff,
+	0x4d, 0xe4, 0x3b, 0x49, 0xfa, 0x82, 0xd8, 0x0a, 0x4b, 0x80, 0xf8, 0x43, 0x4a,
+]);
+
+/// The SHA3-256 of the RLP encoding of empty data.
+pub const SHA3_NULL_RLP: H256 = H256([
+	0xbc, 0x20, 0x71, 0xa4, 0xde, 0x84, 0x6f, 0x28, 0x57, 0x02, 0x44, 0x7f, 0x25, 0x89, 0xdd, 0x16, 0x36, 0x78, 0xe0,
+	0x97, 0x2a, 0x8a, 0x1b, 0x0d, 0x28, 0xb0, 0x4e, 0xd5, 0xc0, 0x94, 0x54, 0x7f,
+]);
+
+/// The SHA3-256 of the RLP encoding of empty list.
+pub const SHA3_EMPTY_LIST_RLP: H256 = H256([
+	0xf1, 0x8f, 0x47, 0x84, 0x8f, 0xb2, 0x93, 0x46, 0x8f, 0x64, 0x1c, 0x33, 0x86, 0x3d, 0xca, 0x9e, 0x52, 0x78, 0xfa,
+	0x8e, 0x96, 0x90, 0xf7, 0x7f, 0x7d, 0xc9, 0x6e, 0x95, 0x4e, 0xf9, 0x22, 0x1b,
+]);
+
+/// The hash of the empty bytes string under [`Algorithm::DEFAULT`].
+pub const HASH_EMPTY: H256 = Algorithm::DEFAULT.empty();
+
+/// The hash of the RLP encoding of empty data under [`Algorithm::DEFAULT`].
+pub const HASH_NULL_RLP: H256 = Algorithm::DEFAULT.null_rlp();
+
+/// The hash of the RLP encoding of empty list under [`Algorithm::DEFAULT`].
+pub const HASH_EMPTY_LIST_RLP: H256 = Algorithm::DEFAULT.empty_list_rlp();
+
+/// The 256 and 512-bit hash family to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+	/// Keccak with the original padding, as used by Ethereum.
+	Keccak,
+	/// NIST SHA3 (FIPS 202).
+	Sha3,
+}
+
+impl Algorithm {
+	/// [`Algorithm::Sha3`] with the `sha3-default` feature, [`Algorithm::Keccak`] otherwise.
+	#[cfg(feature = "sha3-default")]
+	pub const DEFAULT: Algorithm = Algorithm::Sha3;
+	/// [`Algorithm::Sha3`] with the `sha3-default` feature, [`Algorithm::Keccak`] otherwise.
+	#[cfg(not(feature = "sha3-default"))]
+	pub const DEFAULT: Algorithm = Algorithm::Keccak;
+
+	/// The 256-bit hash of `s`.
+	pub fn hash<T: AsRef<[u8]>>(self, s: T) -> H256 {
+		let mut result = [0u8; 32];
+		self.hash_256(s.as_ref(), &mut result);
+		H256(result)
+	}
+
+	/// The 256-bit hashes of all `inputs`, in order. See [`keccak_batch`].
+	pub fn hash_batch<T: AsRef<[u8]> + Sync>(self, inputs: &[T]) -> Vec<H256> {
+		match self {
+			Algorithm::Keccak => keccak_batch(inputs),
+			Algorithm::Sha3 => sha3_batch(inputs),
+		}
+	}
+
+	/// Writes the 256-bit hash of `input` to `output`.
+	pub fn hash_256(self, input: &[u8], output: &mut [u8]) {
+		match self {
+			Algorithm::Keccak => keccak_256(input, output),
+			Algorithm::Sha3 => sha3_256(input, output),
+		}
+	}
+
+	/// Writes the 512-bit hash of `input` to `output`.
+	pub fn hash_512(self, input: &[u8], output: &mut [u8]) {
+		match self {
+			Algorithm::Keccak => keccak_512(input, output),
+			Algorithm::Sha3 => sha3_512(input, output),
+		}
+	}
+
+	/// The hash of the empty bytes string.
+	pub const fn empty(self) -> H256 {
+		match self {
+			Algorithm::Keccak => KECCAK_EMPTY,
+			Algorithm::Sha3 => SHA3_EMPTY,
+		}
+	}
+
+	/// The hash of the RLP encoding of empty data.
+	pub const fn null_rlp(self) -> H256 {
+		match self {
+			Algorithm::Keccak => KECCAK_NULL_RLP,
+			Algorithm::Sha3 => SHA3_NULL_RLP,
+		}
+	}
+
+	/// The hash of the RLP encoding of empty list.
+	pub const fn empty_list_rlp(self) -> H256 {
+		match self {
+			Algorithm::Keccak => KECCAK_EMPTY_LIST_RLP,
+			Algorithm::Sha3 => SHA3_EMPTY_LIST_RLP,
+		}
+	}
+
+	/// Hashes everything read from `r` while copying it to `w`.
+	#[cfg(feature = "std")]
+	pub fn pipe(self, r: &mut dyn io::BufRead, w: &mut dyn io::Write) -> Result<H256, io::Error> {
+		match self {
+			Algorithm::Keccak => keccak_pipe(r, w),
+			Algorithm::Sha3 => sha3_pipe(r, w),
+		}
+	}
+}
+
+impl Default for Algorithm {
+	fn default() -> Self {
+		Algorithm::DEFAULT
+	}
+}
+
+/// The 256-bit hash of `s` under [`Algorithm::DEFAULT`].
+pub fn hash<T: AsRef<[u8]>>(s: T) -> H256 {
+	Algorithm::DEFAULT.hash(s)
+}
+
 pub fn keccak<T: AsRef<[u8]>>(s: T) -> H256 {
 	let mut result = [0u8; 32];
 	write_keccak(s, &mut result);
@@ -119,11 +258,134 @@ pub fn write_keccak<T: AsRef<[u8]>>(s: T, dest: &mut [u8]) {
 	keccak256.finalize(dest);
 }
 
+pub fn sha3<T: AsRef<[u8]>>(s: T) -> H256 {
+	let mut result = [0u8; 32];
+	write_sha3(s, &mut result);
+	H256(result)
+}
+
+/// Computes in-place SHA3-256 hash of `data`.
+pub fn sha3256(data: &mut [u8]) {
+	let mut sha3256 = Sha3::v256();
+	sha3256.update(data.as_ref());
+	sha3256.finalize(data);
+}
+
+/// Computes in-place SHA3-256 hash of `data[range]`.
+///
+/// The `range` argument specifies a subslice of `data` in bytes to be hashed.
+/// The resulting hash will be written back to `data`.
+/// # Panics
+///
+/// If `range` is out of bounds.
+///
+/// # Example
+///
+/// ```
+/// let mut data = [1u8; 32];
+/// // Hash the first 8 bytes of `data` and write the result, 32 bytes, to `data`.
+/// keccak_hash::sha3256_range(&mut data, 0..8);
+/// let expected = [
+///     0x1c, 0x7a, 0xb5, 0xd4, 0x0b, 0x93, 0x93, 0xad, 0xdc, 0x66, 0x96, 0x08, 0x64, 0xa4, 0x52, 0x78,
+///     0xe4, 0xfd, 0x35, 0xa0, 0x05, 0x15, 0x67, 0x01, 0x7f, 0xf3, 0xd7, 0x39, 0x58, 0x61, 0x89, 0x71,
+/// ];
+/// assert_eq!(&data, &expected);
+/// ```
+pub fn sha3256_range(data: &mut [u8], range: core::ops::Range<usize>) {
+	let mut sha3256 = Sha3::v256();
+	sha3256.update(&data[range]);
+	sha3256.finalize(data);
+}
+
+/// Computes in-place SHA3-512 hash of `data`.
+pub fn sha3512(data: &mut [u8]) {
+	let mut sha3512 = Sha3::v512();
+	sha3512.update(data.as_ref());
+	sha3512.finalize(data);
+}
+
+/// Computes in-place SHA3-512 hash of `data[range]`.
+///
+/// The `range` argument specifies a subslice of `data` in bytes to be hashed.
+/// The resulting hash will be written back to `data`.
+/// # Panics
+///
+/// If `range` is out of bounds.
+///
+/// # Example
+///
+/// ```
+/// let mut data = [1u8; 64];
+/// keccak_hash::sha3512_range(&mut data, 0..8);
+/// let expected = [
+///     0x29, 0x56, 0x6a, 0xc0, 0x86, 0xef, 0xc4, 0x8e, 0xb2, 0xaf, 0x76, 0x1b, 0x91, 0xd8, 0x35, 0x07,
+///     0x9e, 0xa5, 0xc7, 0x9e, 0x73, 0x6a, 0xc2, 0x4e, 0xe4, 0x0e, 0x88, 0x1a, 0xbf, 0xe1, 0x20, 0xc1,
+/// ];
+/// assert_eq!(&data[..32], &expected);
+/// ```
+pub fn sha3512_range(data: &mut [u8], range: core::ops::Range<usize>) {
+	let mut sha3512 = Sha3::v512();
+	sha3512.update(&data[range]);
+	sha3512.finalize(data);
+}
+
+pub fn sha3_256(input: &[u8], output: &mut [u8]) {
+	write_sha3(input, output);
+}
+
+pub fn sha3_512(input: &[u8], output: &mut [u8]) {
+	let mut sha3512 = Sha3::v512();
+	sha3512.update(input);
+	sha3512.finalize(output);
+}
+
+pub fn write_sha3<T: AsRef<[u8]>>(s: T, dest: &mut [u8]) {
+	let mut sha3256 = Sha3::v256();
+	sha3256.update(s.as_ref());
+	sha3256.finalize(dest);
+}
+
+/// Batches with at least this many inputs are hashed in parallel with the `rayon` feature.
+#[cfg(feature = "rayon")]
+const PARALLEL_BATCH_LEN: usize = 64;
+
+fn batch<T: AsRef<[u8]> + Sync>(inputs: &[T], hash: fn(&[u8]) -> H256) -> Vec<H256> {
+	#[cfg(feature = "rayon")]
+	{
+		use rayon::prelude::*;
+
+		if inputs.len() >= PARALLEL_BATCH_LEN {
+			return inputs.par_iter().map(|input| hash(input.as_ref())).collect()
+		}
+	}
+	inputs.iter().map(|input| hash(input.as_ref())).collect()
+}
+
+/// Computes the keccak256 hashes of all `inputs`, in order.
+///
+/// With the `rayon` feature, large batches are spread over the rayon thread pool.
+///
+/// # Example
+///
+/// ```
+/// use keccak_hash::{keccak, keccak_batch};
+///
+/// let hashes = keccak_batch(&[&b"foo"[..], &b"bar"[..]]);
+/// assert_eq!(hashes, vec![keccak(b"foo"), keccak(b"bar")]);
+/// ```
+pub fn keccak_batch<T: AsRef<[u8]> + Sync>(inputs: &[T]) -> Vec<H256> {
+	batch(inputs, |input| keccak(input))
+}
+
+/// Computes the SHA3-256 hashes of all `inputs`, in order, like [`keccak_batch`].
+pub fn sha3_batch<T: AsRef<[u8]> + Sync>(inputs: &[T]) -> Vec<H256> {
+	batch(inputs, |input| sha3(input))
+}
+
 #[cfg(feature = "std")]
-pub fn keccak_pipe(r: &mut dyn io::BufRead, w: &mut dyn io::Write) -> Result<H256, io::Error> {
+fn pipe<H: Hasher>(mut hasher: H, r: &mut dyn io::BufRead, w: &mut dyn io::Write) -> Result<H256, io::Error> {
 	let mut output = [0u8; 32];
 	let mut input = [0u8; 1024];
-	let mut keccak256 = Keccak::v256();
 
 	// read file
 	loop {
@@ -131,19 +393,34 @@ pub fn keccak_pipe(r: &mut dyn io::BufRead, w: &mut dyn io::Write) -> Result<H25
 		if some == 0 {
 			break
 		}
-		keccak256.update(&input[0..some]);
+		hasher.update(&input[0..some]);
 		w.write_all(&input[0..some])?;
 	}
 
-	keccak256.finalize(&mut output);
+	hasher.finalize(&mut output);
 	Ok(output.into())
 }
 
+#[cfg(feature = "std")]
+pub fn keccak_pipe(r: &mut dyn io::BufRead, w: &mut dyn io::Write) -> Result<H256, io::Error> {
+	pipe(Keccak::v256(), r, w)
+}
+
 #[cfg(feature = "std")]
 pub fn keccak_buffer(r: &mut dyn io::BufRead) -> Result<H256, io::Error> {
 	keccak_pipe(r, &mut io::sink())
 }
 
+#[cfg(feature = "std")]
+pub fn sha3_pipe(r: &mut dyn io::BufRead, w: &mut dyn io::Write) -> Result<H256, io::Error> {
+	pipe(Sha3::v256(), r, w)
+}
+
+#[cfg(feature = "std")]
+pub fn sha3_buffer(r: &mut dyn io::BufRead) -> Result<H256, io::Error> {
+	sha3_pipe(r, &mut io::sink())
+}
+
 #[cfg(test)]
 mod tests {
 	#[cfg(not(feature = "std"))]
@@ -182,6 +459,85 @@ mod tests {
 		assert_eq!(dest, expected.as_ref());
 	}
 
+	#[test]
+	fn sha3_empty() {
+		assert_eq!(sha3([0u8; 0]), SHA3_EMPTY);
+		assert_eq!(sha3([0x80u8]), SHA3_NULL_RLP);
+		assert_eq!(sha3([0xc0u8]), SHA3_EMPTY_LIST_RLP);
+		assert_eq!(keccak([0x80u8]), KECCAK_NULL_RLP);
+		assert_eq!(keccak([0xc0u8]), KECCAK_EMPTY_LIST_RLP);
+	}
+
+	// FIPS 202 example values for the one and two block messages.
+	#[test]
+	fn sha3_nist_vectors() {
+		use primitive_types::H512;
+
+		let two_blocks = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+
+		assert_eq!(sha3(b"abc"), "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532".parse().unwrap());
+		assert_eq!(sha3(two_blocks), "41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376".parse().unwrap());
+
+		let mut out = [0u8; 64];
+		sha3_512(b"", &mut out);
+		assert_eq!(
+			H512(out),
+			"a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26".parse().unwrap(),
+		);
+		sha3_512(b"abc", &mut out);
+		assert_eq!(
+			H512(out),
+			"b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0".parse().unwrap(),
+		);
+		sha3_512(two_blocks, &mut out);
+		assert_eq!(
+			H512(out),
+			"04a371e84ecfb5b8b77cb48610fca8182dd457ce6f326a0fd3d7ec2f1e91636dee691fbe0c985302ba1b0d8dc78c086346b533b49c030d99a27daf1139d6e75e".parse().unwrap(),
+		);
+	}
+
+	#[test]
+	fn sha3_in_place() {
+		let mut data = [0u8; 32];
+		data[..3].copy_from_slice(b"abc");
+		sha3256_range(&mut data, 0..3);
+		assert_eq!(H256(data), sha3(b"abc"));
+
+		let mut data = [0x41u8; 64];
+		let mut expected = [0u8; 64];
+		sha3_512(&data, &mut expected);
+		sha3512(&mut data);
+		assert_eq!(data, expected);
+	}
+
+	#[test]
+	fn algorithm() {
+		assert_eq!(Algorithm::Keccak.hash(b"abc"), keccak(b"abc"));
+		assert_eq!(Algorithm::Sha3.hash(b"abc"), sha3(b"abc"));
+		assert_eq!(Algorithm::Sha3.null_rlp(), SHA3_NULL_RLP);
+		assert_eq!(Algorithm::default(), Algorithm::DEFAULT);
+		assert_eq!(hash([0u8; 0]), HASH_EMPTY);
+		#[cfg(feature = "sha3-default")]
+		assert_eq!(HASH_NULL_RLP, SHA3_NULL_RLP);
+		#[cfg(not(feature = "sha3-default"))]
+		assert_eq!(HASH_NULL_RLP, KECCAK_NULL_RLP);
+	}
+
+	#[test]
+	fn batch() {
+		assert_eq!(keccak_batch::<&[u8]>(&[]), Vec::new());
+
+		// long enough to go parallel with the `rayon` feature
+		let inputs: Vec<Vec<u8>> = (0..200u32).map(|i| i.to_be_bytes().repeat(i as usize % 50)).collect();
+		let hashes = keccak_batch(&inputs);
+		assert_eq!(hashes.len(), inputs.len());
+		for (input, hash) in inputs.iter().zip(&hashes) {
+			assert_eq!(keccak(input), *hash);
+		}
+		assert_eq!(sha3_batch(&inputs[..3]), vec![SHA3_EMPTY, sha3([0, 0, 0, 1]), sha3([0, 0, 0, 2, 0, 0, 0, 2])]);
+		assert_eq!(Algorithm::Sha3.hash_batch(&inputs), sha3_batch(&inputs));
+	}
+
 	#[cfg(feature = "std")]
 	#[test]
 	fn should_keccak_a_file() {
@@ -207,4 +563,16 @@ mod tests {
 		// then
 		assert_eq!(format!("{:x}", hash), "68371d7e884c168ae2022c82bd837d51837718a7f7dfb7aa3f753074a35e1d87");
 	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn should_sha3_a_pipe() {
+		let mut copy = Vec::new();
+		let hash = sha3_pipe(&mut &b"something"[..], &mut copy).unwrap();
+
+		assert_eq!(copy, b"something");
+		assert_eq!(format!("{:x}", hash), "f45617945f7f89deb81173f0bd29fde37b698d9cd1cc7a99fb3fa3c189241ce7");
+		assert_eq!(sha3_buffer(&mut &b"something"[..]).unwrap(), hash);
+		assert_eq!(Algorithm::Keccak.pipe(&mut &b"something"[..], &mut io::sink()).unwrap(), keccak(b"something"));
+	}
 }
@@ -0,0 +1,545 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Binary Merkle tree over `H256` leaves.
+//!
+//! Leaves and nodes are hashed with distinct prefixes, as in RFC 6962: a leaf
+//! is hashed as `0x00 ‖ leaf` and a parent as `0x01 ‖ left ‖ right`, so a node
+//! can never pass for a leaf. A node without a sibling, the last one of a
+//! layer with an odd length, moves up to the next layer unchanged. The top node
+//! of a single leaf is its leaf hash, and the top node of no leaves is the
+//! hash of the empty string.
+//!
+//! The root commits to the number of leaves: it is the hash of
+//! `0x02 ‖ leaf count ‖ top node`, with the leaf count as 8 big-endian bytes.
+//! A proof that verifies thus shows that the leaf is at the proof's index in
+//! a tree with the proof's number of leaves.
+
+use crate::{Algorithm, H256};
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+/// A binary Merkle tree, with all layers kept for making proofs.
+///
+/// ```
+/// use keccak_hash::{keccak, Algorithm, MerkleTree};
+///
+/// let leaves: Vec<_> = ["a", "b", "c"].iter().map(keccak).collect();
+/// let tree = MerkleTree::with_algorithm(leaves.clone(), Algorithm::Sha3);
+/// let proof = tree.proof(2).unwrap();
+/// assert!(proof.verify(Algorithm::Sha3, &tree.root(), &leaves[2]));
+///
+/// let multiproof = tree.multiproof(&[0, 2]).unwrap();
+/// assert!(multiproof.verify(Algorithm::Sha3, &tree.root(), &[leaves[0], leaves[2]]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleTree {
+	algorithm: Algorithm,
+	leaves: Vec<H256>,
+	// `layers[0]` holds the leaf hashes and the last layer the root.
+	layers: Vec<Vec<H256>>,
+}
+
+impl MerkleTree {
+	/// Builds the tree over `leaves`, hashing nodes with `algorithm`.
+	///
+	/// There is no constructor using [`Algorithm::DEFAULT`], as any crate in the
+	/// dependency graph can flip it with the `sha3-default` feature and so
+	/// change every root.
+	pub fn with_algorithm(leaves: Vec<H256>, algorithm: Algorithm) -> Self {
+		let mut layers: Vec<Vec<H256>> = vec![leaves.iter().map(|leaf| hash_leaf(algorithm, leaf)).collect()];
+		while let Some(layer) = layers.last().filter(|layer| layer.len() > 1) {
+			let next = layer
+				.chunks(2)
+				.map(|pair| match *pair {
+					[left, right] => hash_pair(algorithm, &left, &right),
+					[single] => single,
+					_ => unreachable!("chunks(2) yields one or two nodes"),
+				})
+				.collect();
+			layers.push(next);
+		}
+		MerkleTree { algorithm, leaves, layers }
+	}
+
+	/// The algorithm used to hash nodes.
+	pub fn algorithm(&self) -> Algorithm {
+		self.algorithm
+	}
+
+	/// The root hash, committing to the number of leaves.
+	pub fn root(&self) -> H256 {
+		let top = match self.layers.last().and_then(|layer| layer.first()) {
+			Some(top) => *top,
+			None => self.algorithm.empty(),
+		};
+		hash_root(self.algorithm, self.len() as u64, &top)
+	}
+
+	/// The leaves, in order.
+	pub fn leaves(&self) -> &[H256] {
+		&self.leaves
+	}
+
+	/// The number of leaves.
+	pub fn len(&self) -> usize {
+		self.leaves.len()
+	}
+
+	/// Returns `true` if the tree has no leaves.
+	pub fn is_empty(&self) -> bool {
+		self.leaves.is_empty()
+	}
+
+	/// A proof that the leaf at `index` is in the tree, or `None` if there is
+	/// no such leaf.
+	pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+		if index >= self.len() {
+			return None
+		}
+		let mut siblings = Vec::with_capacity(self.layers.len() - 1);
+		let mut position = index;
+		for layer in &self.layers[..self.layers.len() - 1] {
+			if let Some(sibling) = layer.get(position ^ 1) {
+				siblings.push(*sibling);
+			}
+			position /= 2;
+		}
+		Some(MerkleProof { index: index as u64, leaf_count: self.len() as u64, siblings })
+	}
+
+	/// A proof that all leaves at `indices` are in the tree, sharing the
+	/// hashes they have in common, or `None` if `indices` is empty or any
+	/// index is out of bounds.
+	///
+	/// The indices may come in any order and repeat. The proof holds them
+	/// sorted and deduplicated, which is the order the leaves are passed to
+	/// [`MerkleMultiProof::verify`] in.
+	pub fn multiproof(&self, indices: &[usize]) -> Option<MerkleMultiProof> {
+		let mut known = indices.to_vec();
+		known.sort_unstable();
+		known.dedup();
+		match known.last() {
+			Some(&index) if index < self.len() => {},
+			_ => return None,
+		}
+		let proof_indices = known.iter().map(|&index| index as u64).collect();
+
+		let mut hashes = Vec::new();
+		for layer in &self.layers[..self.layers.len() - 1] {
+			let mut next = Vec::with_capacity(known.len());
+			let mut i = 0;
+			while i < known.len() {
+				let position = known[i];
+				let sibling = position ^ 1;
+				if known.get(i + 1) == Some(&sibling) {
+					i += 1;
+				} else if let Some(hash) = layer.get(sibling) {
+					hashes.push(*hash);
+				}
+				next.push(position / 2);
+				i += 1;
+			}
+			known = next;
+		}
+		Some(MerkleMultiProof { indices: proof_indices, leaf_count: self.len() as u64, hashes })
+	}
+}
+
+/// Inclusion proof for a single leaf of a [`MerkleTree`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub struct MerkleProof {
+	/// Index of the leaf.
+	pub index: u64,
+	/// Number of leaves in the tree.
+	pub leaf_count: u64,
+	/// Sibling hashes from the leaf up, leaving out layers where the node
+	/// has no sibling.
+	pub siblings: Vec<H256>,
+}
+
+impl MerkleProof {
+	/// The root of the tree the proof is for, if `leaf` is at its index, or
+	/// `None` if the proof is malformed.
+	pub fn compute_root(&self, algorithm: Algorithm, leaf: &H256) -> Option<H256> {
+		if self.index >= self.leaf_count {
+			return None
+		}
+		let mut siblings = self.siblings.iter();
+		let mut hash = hash_leaf(algorithm, leaf);
+		let mut position = self.index;
+		let mut layer_len = self.leaf_count;
+		while layer_len > 1 {
+			if position % 2 == 1 {
+				hash = hash_pair(algorithm, siblings.next()?, &hash);
+			} else if position + 1 < layer_len {
+				hash = hash_pair(algorithm, &hash, siblings.next()?);
+			}
+			position /= 2;
+			layer_len = (layer_len + 1) / 2;
+		}
+		match siblings.next() {
+			Some(_) => None,
+			None => Some(hash_root(algorithm, self.leaf_count, &hash)),
+		}
+	}
+
+	/// Returns `true` if `leaf` is at the proof's index in the tree with `root`.
+	pub fn verify(&self, algorithm: Algorithm, root: &H256, leaf: &H256) -> bool {
+		self.compute_root(algorithm, leaf).as_ref() == Some(root)
+	}
+}
+
+/// Inclusion proof for several leaves of a [`MerkleTree`] at once.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
+pub struct MerkleMultiProof {
+	/// Indices of the leaves, strictly increasing.
+	pub indices: Vec<u64>,
+	/// Number of leaves in the tree.
+	pub leaf_count: u64,
+	/// The hashes that cannot be computed from the leaves, layer by layer
+	/// from the leaves up, and left to right within a layer.
+	pub hashes: Vec<H256>,
+}
+
+impl MerkleMultiProof {
+	/// The root of the tree the proof is for, if `leaves` are at its indices,
+	/// or `None` if the proof is malformed or the number of leaves is wrong.
+	pub fn compute_root(&self, algorithm: Algorithm, leaves: &[H256]) -> Option<H256> {
+		if leaves.is_empty() || leaves.len() != self.indices.len() {
+			return None
+		}
+		if self.indices.windows(2).any(|pair| pair[0] >= pair[1]) || self.indices.last()? >= &self.leaf_count {
+			return None
+		}
+
+		let mut known: Vec<(u64, H256)> =
+			self.indices.iter().copied().zip(leaves.iter().map(|leaf| hash_leaf(algorithm, leaf))).collect();
+		let mut hashes = self.hashes.iter();
+		let mut layer_len = self.leaf_count;
+		while layer_len > 1 {
+			let mut next = Vec::with_capacity(known.len());
+			let mut i = 0;
+			while i < known.len() {
+				let (position, hash) = known[i];
+				let parent = if position % 2 == 1 {
+					hash_pair(algorithm, hashes.next()?, &hash)
+				} else if position + 1 < layer_len {
+					let right = match known.get(i + 1) {
+						Some(&(next_position, right)) if next_position == position + 1 => {
+							i += 1;
+							right
+						},
+						_ => *hashes.next()?,
+					};
+					hash_pair(algorithm, &hash, &right)
+				} else {
+					hash
+				};
+				next.push((position / 2, parent));
+				i += 1;
+			}
+			known = next;
+			layer_len = (layer_len + 1) / 2;
+		}
+		match hashes.next() {
+			Some(_) => None,
+			None => Some(hash_root(algorithm, self.leaf_count, &known[0].1)),
+		}
+	}
+
+	/// Returns `true` if `leaves` are at the proof's indices in the tree with `root`.
+	pub fn verify(&self, algorithm: Algorithm, root: &H256, leaves: &[H256]) -> bool {
+		self.compute_root(algorithm, leaves).as_ref() == Some(root)
+	}
+}
+
+#[cfg(feature = "rlp")]
+mod rlp_impls {
+	use super::{MerkleMultiProof, MerkleProof};
+	use crate::H256;
+	#[cfg(not(feature = "std"))]
+	use alloc::vec::Vec;
+	use rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
+
+	fn append_hashes(s: &mut RlpStream, hashes: &[H256]) {
+		s.begin_list(hashes.len());
+		for hash in hashes {
+			s.append(&hash.as_bytes());
+		}
+	}
+
+	fn decode_hashes(rlp: &Rlp) -> Result<Vec<H256>, DecoderError> {
+		rlp.iter()
+			.map(|item| {
+				item.decoder().decode_value(|bytes| match bytes.len() {
+					32 => Ok(H256::from_slice(bytes)),
+					0..=31 => Err(DecoderError::RlpIsTooShort),
+					_ => Err(DecoderError::RlpIsTooBig),
+				})
+			})
+			.collect()
+	}
+
+	impl Encodable for MerkleProof {
+		fn rlp_append(&self, s: &mut RlpStream) {
+			s.begin_list(3);
+			s.append(&self.index);
+			s.append(&self.leaf_count);
+			append_hashes(s, &self.siblings);
+		}
+	}
+
+	impl Decodable for MerkleProof {
+		fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+			if rlp.item_count()? != 3 {
+				return Err(DecoderError::RlpIncorrectListLen)
+			}
+			Ok(MerkleProof { index: rlp.val_at(0)?, leaf_count: rlp.val_at(1)?, siblings: decode_hashes(&rlp.at(2)?)? })
+		}
+	}
+
+	impl Encodable for MerkleMultiProof {
+		fn rlp_append(&self, s: &mut RlpStream) {
+			s.begin_list(3);
+			s.append_list(&self.indices);
+			s.append(&self.leaf_count);
+			append_hashes(s, &self.hashes);
+		}
+	}
+
+	impl Decodable for MerkleMultiProof {
+		fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+			if rlp.item_count()? != 3 {
+				return Err(DecoderError::RlpIncorrectListLen)
+			}
+			Ok(MerkleMultiProof { indices: rlp.list_at(0)?, leaf_count: rlp.val_at(1)?, hashes: decode_hashes(&rlp.at(2)?)? })
+		}
+	}
+}
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+const ROOT_PREFIX: u8 = 0x02;
+
+fn hash_leaf(algorithm: Algorithm, leaf: &H256) -> H256 {
+	let mut input = [LEAF_PREFIX; 33];
+	input[1..].copy_from_slice(leaf.as_bytes());
+	algorithm.hash(input)
+}
+
+fn hash_pair(algorithm: Algorithm, left: &H256, right: &H256) -> H256 {
+	let mut input = [NODE_PREFIX; 65];
+	input[1..33].copy_from_slice(left.as_bytes());
+	input[33..].copy_from_slice(right.as_bytes());
+	algorithm.hash(input)
+}
+
+fn hash_root(algorithm: Algorithm, leaf_count: u64, top: &H256) -> H256 {
+	let mut input = [ROOT_PREFIX; 41];
+	input[1..9].copy_from_slice(&leaf_count.to_be_bytes());
+	input[9..].copy_from_slice(top.as_bytes());
+	algorithm.hash(input)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{keccak, sha3, KECCAK_EMPTY};
+
+	fn leaves(n: u64) -> Vec<H256> {
+		(0..n).map(|i| keccak(i.to_be_bytes())).collect()
+	}
+
+	fn concat(left: &H256, right: &H256) -> Vec<u8> {
+		[left.as_bytes(), right.as_bytes()].concat()
+	}
+
+	fn leaf(leaf: &H256) -> Vec<u8> {
+		[&[0x00][..], leaf.as_bytes()].concat()
+	}
+
+	fn node(left: &H256, right: &H256) -> Vec<u8> {
+		[&[0x01][..], left.as_bytes(), right.as_bytes()].concat()
+	}
+
+	fn commit(leaf_count: u64, top: &H256) -> Vec<u8> {
+		[&[0x02][..], &leaf_count.to_be_bytes(), top.as_bytes()].concat()
+	}
+
+	#[test]
+	fn roots() {
+		assert_eq!(MerkleTree::with_algorithm(vec![], Algorithm::Keccak).root(), keccak(commit(0, &KECCAK_EMPTY)));
+		let l = leaves(5);
+		let tree = MerkleTree::with_algorithm(l[..1].to_vec(), Algorithm::Keccak);
+		assert_eq!(tree.root(), keccak(commit(1, &keccak(leaf(&l[0])))));
+
+		// ((l0 l1) (l2 l3)) l4, with l4 moving up unchanged
+		let h: Vec<H256> = l.iter().map(|l| keccak(leaf(l))).collect();
+		let l01 = keccak(node(&h[0], &h[1]));
+		let l23 = keccak(node(&h[2], &h[3]));
+		let top = keccak(node(&keccak(node(&l01, &l23)), &h[4]));
+		assert_eq!(MerkleTree::with_algorithm(l.clone(), Algorithm::Keccak).root(), keccak(commit(5, &top)));
+
+		let tree = MerkleTree::with_algorithm(l[..2].to_vec(), Algorithm::Sha3);
+		assert_eq!(tree.root(), sha3(commit(2, &sha3(node(&sha3(leaf(&l[0])), &sha3(leaf(&l[1])))))));
+		assert_eq!(tree.algorithm(), Algorithm::Sha3);
+		assert_eq!(tree.leaves(), &l[..2]);
+		assert_eq!(tree.len(), 2);
+	}
+
+	#[test]
+	fn proofs() {
+		for n in 1..=17 {
+			let tree = MerkleTree::with_algorithm(leaves(n), Algorithm::Sha3);
+			let root = tree.root();
+			for (index, leaf) in tree.leaves().iter().enumerate() {
+				let proof = tree.proof(index).unwrap();
+				assert!(proof.verify(Algorithm::Sha3, &root, leaf), "leaf {} of {}", index, n);
+				assert!(!proof.verify(Algorithm::Keccak, &root, leaf));
+				assert!(!proof.verify(Algorithm::Sha3, &root, &keccak(b"other")));
+				for other in (0..n).filter(|&other| other != index as u64) {
+					let moved = MerkleProof { index: other, ..proof.clone() };
+					assert!(!moved.verify(Algorithm::Sha3, &root, leaf), "leaf {} of {} as {}", index, n, other);
+				}
+			}
+			assert_eq!(tree.proof(n as usize), None);
+		}
+	}
+
+	#[test]
+	fn node_is_not_a_leaf() {
+		let l = leaves(4);
+		let tree = MerkleTree::with_algorithm(l.clone(), Algorithm::Keccak);
+		let root = tree.root();
+
+		// the root of [l0, l1] and [l2, l3] passed off as a two leaf tree
+		let forged = MerkleProof { index: 0, leaf_count: 2, siblings: vec![tree.layers[1][1]] };
+		assert!(!forged.verify(Algorithm::Keccak, &root, &tree.layers[1][0]));
+		let forged = MerkleProof { index: 0, leaf_count: 2, siblings: vec![keccak(concat(&l[2], &l[3]))] };
+		assert!(!forged.verify(Algorithm::Keccak, &root, &keccak(concat(&l[0], &l[1]))));
+
+		let three = MerkleTree::with_algorithm(l[..3].to_vec(), Algorithm::Keccak);
+		let folded = MerkleTree::with_algorithm(vec![keccak(concat(&l[0], &l[1])), l[2]], Algorithm::Keccak);
+		assert_ne!(three.root(), folded.root());
+		let folded = MerkleTree::with_algorithm(vec![three.layers[1][0], l[2]], Algorithm::Keccak);
+		assert_ne!(three.root(), folded.root());
+	}
+
+	#[test]
+	fn proof_is_bound_to_leaf_count() {
+		let tree = MerkleTree::with_algorithm(leaves(5), Algorithm::Keccak);
+		let root = tree.root();
+		let leaf = tree.leaves()[4];
+		let proof = tree.proof(4).unwrap();
+		// l4 moves up unchanged until it meets n0123
+		assert_eq!(proof.siblings, vec![tree.layers[2][0]]);
+		assert!(proof.verify(Algorithm::Keccak, &root, &leaf));
+
+		// the same hashing as leaf 1 of 2, which the leaf count in the root rules out
+		let moved = MerkleProof { index: 1, leaf_count: 2, ..proof };
+		assert!(!moved.verify(Algorithm::Keccak, &root, &leaf));
+		let top = tree.layers[3][0];
+		assert_eq!(moved.compute_root(Algorithm::Keccak, &leaf), Some(keccak(commit(2, &top))));
+	}
+
+	#[test]
+	fn tampered_proofs() {
+		let tree = MerkleTree::with_algorithm(leaves(6), Algorithm::Keccak);
+		let root = tree.root();
+		let leaf = tree.leaves()[5];
+		let proof = tree.proof(5).unwrap();
+		assert_eq!(proof.siblings.len(), 2);
+
+		let mut moved = proof.clone();
+		moved.index = 4;
+		assert!(!moved.verify(Algorithm::Keccak, &root, &leaf));
+		let mut resized = proof.clone();
+		resized.leaf_count = 8;
+		assert!(!resized.verify(Algorithm::Keccak, &root, &leaf));
+		let mut extended = proof;
+		extended.siblings.push(root);
+		assert_eq!(extended.compute_root(Algorithm::Keccak, &leaf), None);
+	}
+
+	#[test]
+	fn multiproofs() {
+		for n in 1..=10 {
+			let tree = MerkleTree::with_algorithm(leaves(n), Algorithm::Keccak);
+			let root = tree.root();
+			// every non-empty subset of the leaves
+			for mask in 1u32..(1 << n) {
+				let indices: Vec<usize> = (0..n as usize).filter(|i| mask & (1 << i) != 0).collect();
+				let chosen: Vec<H256> = indices.iter().map(|&i| tree.leaves()[i]).collect();
+				let proof = tree.multiproof(&indices).unwrap();
+				assert!(proof.verify(Algorithm::Keccak, &root, &chosen), "{:?} of {}", indices, n);
+			}
+		}
+	}
+
+	#[test]
+	fn multiproof_shares_hashes() {
+		let tree = MerkleTree::with_algorithm(leaves(8), Algorithm::Keccak);
+		let root = tree.root();
+		let all = tree.multiproof(&[7, 6, 5, 4, 3, 2, 1, 0, 0]).unwrap();
+		assert_eq!(all.indices, vec![0, 1, 2, 3, 4, 5, 6, 7]);
+		assert!(all.hashes.is_empty());
+		assert!(all.verify(Algorithm::Keccak, &root, tree.leaves()));
+
+		let pair = tree.multiproof(&[2, 3]).unwrap();
+		assert_eq!(pair.hashes.len(), 2);
+		let single = tree.multiproof(&[5]).unwrap();
+		assert_eq!(single.hashes, tree.proof(5).unwrap().siblings);
+
+		let leaves = [tree.leaves()[2], tree.leaves()[3]];
+		assert!(!pair.verify(Algorithm::Keccak, &root, &[leaves[1], leaves[0]]));
+		assert!(!pair.verify(Algorithm::Keccak, &root, &leaves[..1]));
+		assert_eq!(tree.multiproof(&[1, 8]), None);
+		assert_eq!(tree.multiproof(&[]), None);
+
+		let mut unsorted = pair;
+		unsorted.indices.swap(0, 1);
+		assert_eq!(unsorted.compute_root(Algorithm::Keccak, &leaves), None);
+	}
+
+	#[cfg(feature = "rlp")]
+	#[test]
+	fn rlp_roundtrip() {
+		let tree = MerkleTree::with_algorithm(leaves(7), Algorithm::Keccak);
+		let proof = tree.proof(6).unwrap();
+		let encoded = rlp::encode(&proof);
+		assert_eq!(rlp::decode::<MerkleProof>(&encoded), Ok(proof));
+
+		let multiproof = tree.multiproof(&[0, 3, 6]).unwrap();
+		let encoded = rlp::encode(&multiproof);
+		assert_eq!(rlp::decode::<MerkleMultiProof>(&encoded), Ok(multiproof));
+
+		// [index, leaf_count, [a hash that is one byte short]]
+		let mut s = rlp::RlpStream::new_list(3);
+		s.append(&0u64).append(&2u64).begin_list(1).append(&&[0u8; 31][..]);
+		assert_eq!(rlp::decode::<MerkleProof>(&s.out()), Err(rlp::DecoderError::RlpIsTooShort));
+	}
+
+	#[cfg(all(feature = "serialize", feature = "std"))]
+	#[test]
+	fn serde_roundtrip() {
+		let tree = MerkleTree::with_algorithm(leaves(3), Algorithm::Keccak);
+		let proof = tree.proof(2).unwrap();
+		let json = serde_json::to_string(&proof).unwrap();
+		assert_eq!(json, format!(r#"{{"index":2,"leafCount":3,"siblings":["{:?}"]}}"#, tree.layers[1][0]));
+		assert_eq!(serde_json::from_str::<MerkleProof>(&json).unwrap(), proof);
+
+		let multiproof = tree.multiproof(&[0, 2]).unwrap();
+		let json = serde_json::to_string(&multiproof).unwrap();
+		assert_eq!(serde_json::from_str::<MerkleMultiProof>(&json).unwrap(), multiproof);
+	}
+}
@@ -0,0 +1,56 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Base58 serde adapter for fixed-size byte types, for use with
+//! `#[serde(with = "impl_serde::base58")]`.
+
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serializes the bytes as a base58 string with the Bitcoin alphabet.
+pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+	T: AsRef<[u8]>,
+{
+	serializer.serialize_str(&bs58::encode(value.as_ref()).into_string())
+}
+
+/// Deserializes a base58 string into exactly as many bytes as `T` holds.
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+	D: Deserializer<'de>,
+	T: Default + AsMut<[u8]>,
+{
+	let s = String::deserialize(deserializer)?;
+	let decoded: Vec<u8> = bs58::decode(&s).into_vec().map_err(serde::de::Error::custom)?;
+	crate::encoding::fill(&decoded)
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::string::ToString;
+	use serde_derive::{Deserialize, Serialize};
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Hash(#[serde(with = "super")] [u8; 4]);
+
+	#[test]
+	fn round_trip() {
+		let json = serde_json::to_string(&Hash([0, 0, 0, 1])).unwrap();
+		assert_eq!(json, r#""1112""#);
+		assert_eq!(serde_json::from_str::<Hash>(&json).unwrap(), Hash([0, 0, 0, 1]));
+	}
+
+	#[test]
+	fn errors() {
+		assert!(serde_json::from_str::<Hash>(r#""0OIl""#).is_err());
+		let err = serde_json::from_str::<Hash>(r#""112""#).unwrap_err();
+		assert!(err.to_string().contains("invalid length 3, expected 4 bytes"), "{}", err);
+	}
+}
@@ -0,0 +1,55 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Base64 serde adapter for fixed-size byte types, for use with
+//! `#[serde(with = "impl_serde::base64")]`.
+
+use ::base64::{engine::general_purpose::STANDARD, Engine};
+use alloc::string::String;
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serializes the bytes as padded base64 with the standard alphabet.
+pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+	T: AsRef<[u8]>,
+{
+	serializer.serialize_str(&STANDARD.encode(value.as_ref()))
+}
+
+/// Deserializes padded base64 into exactly as many bytes as `T` holds.
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+	D: Deserializer<'de>,
+	T: Default + AsMut<[u8]>,
+{
+	let s = String::deserialize(deserializer)?;
+	let decoded = STANDARD.decode(&s).map_err(serde::de::Error::custom)?;
+	crate::encoding::fill(&decoded)
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_derive::{Deserialize, Serialize};
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Hash(#[serde(with = "super")] [u8; 4]);
+
+	#[test]
+	fn round_trip() {
+		let json = serde_json::to_string(&Hash([0xde, 0xad, 0xbe, 0xef])).unwrap();
+		assert_eq!(json, r#""3q2+7w==""#);
+		assert_eq!(serde_json::from_str::<Hash>(&json).unwrap(), Hash([0xde, 0xad, 0xbe, 0xef]));
+	}
+
+	#[test]
+	fn errors() {
+		assert!(serde_json::from_str::<Hash>(r#""3q2+7w""#).is_err());
+		assert!(serde_json::from_str::<Hash>(r#""3q2+7w8=""#).is_err());
+	}
+}
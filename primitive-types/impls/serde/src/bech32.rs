@@ -0,0 +1,110 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Bech32m serde support for fixed-size byte types.
+//!
+//! The human-readable part is a parameter, so these functions cannot be used
+//! with `#[serde(with = ...)]` directly. [`impl_bech32_serde_module!`] creates
+//! a module that can.
+//!
+//! [`impl_bech32_serde_module!`]: crate::impl_bech32_serde_module
+
+use ::bech32::{FromBase32, ToBase32, Variant};
+use alloc::{string::String, vec::Vec};
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+/// Serializes the bytes as bech32m with the human-readable part `hrp`.
+pub fn serialize<S, T>(hrp: &str, value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+	T: AsRef<[u8]>,
+{
+	let encoded = ::bech32::encode(hrp, value.as_ref().to_base32(), Variant::Bech32m)
+		.map_err(serde::ser::Error::custom)?;
+	serializer.serialize_str(&encoded)
+}
+
+/// Deserializes bech32m with the human-readable part `hrp` into exactly as many
+/// bytes as `T` holds.
+pub fn deserialize<'de, D, T>(hrp: &str, deserializer: D) -> Result<T, D::Error>
+where
+	D: Deserializer<'de>,
+	T: Default + AsMut<[u8]>,
+{
+	let s = String::deserialize(deserializer)?;
+	let (prefix, data, variant) = ::bech32::decode(&s).map_err(D::Error::custom)?;
+	if variant != Variant::Bech32m {
+		return Err(D::Error::custom("expected bech32m, got bech32"))
+	}
+	if !prefix.eq_ignore_ascii_case(hrp) {
+		return Err(D::Error::custom(format!("expected human-readable part {}, got {}", hrp, prefix)))
+	}
+	let decoded = Vec::<u8>::from_base32(&data).map_err(D::Error::custom)?;
+	crate::encoding::fill(&decoded)
+}
+
+/// Creates a module that (de)serializes fixed-size byte types as bech32m with
+/// the given human-readable part, for use with `#[serde(with = ...)]`.
+///
+/// ```
+/// impl_serde::impl_bech32_serde_module!(pub mod core_key, "ck");
+///
+/// #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+/// struct Key(#[serde(with = "core_key")] [u8; 4]);
+///
+/// let json = serde_json::to_string(&Key([1, 2, 3, 4])).unwrap();
+/// assert!(json.starts_with("\"ck1"));
+/// assert_eq!(serde_json::from_str::<Key>(&json).unwrap().0, [1, 2, 3, 4]);
+/// ```
+#[macro_export]
+macro_rules! impl_bech32_serde_module {
+	($vis:vis mod $name:ident, $hrp:expr) => {
+		$vis mod $name {
+			pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+			where
+				S: $crate::serde::Serializer,
+				T: AsRef<[u8]>,
+			{
+				$crate::bech32::serialize($hrp, value, serializer)
+			}
+
+			pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+			where
+				D: $crate::serde::Deserializer<'de>,
+				T: Default + AsMut<[u8]>,
+			{
+				$crate::bech32::deserialize($hrp, deserializer)
+			}
+		}
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::string::ToString;
+	use serde_derive::{Deserialize, Serialize};
+
+	impl_bech32_serde_module!(mod core_hash, "core");
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Hash(#[serde(with = "core_hash")] [u8; 4]);
+
+	#[test]
+	fn round_trip() {
+		let json = serde_json::to_string(&Hash([0xde, 0xad, 0xbe, 0xef])).unwrap();
+		assert_eq!(json, r#""core1m6kmamcetk756""#);
+		assert_eq!(serde_json::from_str::<Hash>(&json).unwrap(), Hash([0xde, 0xad, 0xbe, 0xef]));
+	}
+
+	#[test]
+	fn errors() {
+		assert!(serde_json::from_str::<Hash>(r#""core1m6kmamcetk757""#).is_err());
+		let err = serde_json::from_str::<Hash>(r#""cora1m6kmamckvkgam""#).unwrap_err();
+		assert!(err.to_string().contains("human-readable part"), "{}", err);
+	}
+}
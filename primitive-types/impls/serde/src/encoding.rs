@@ -0,0 +1,22 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Shared by the base58, base64 and bech32 adapters.
+pub(crate) fn fill<T, E>(decoded: &[u8]) -> Result<T, E>
+where
+	T: Default + AsMut<[u8]>,
+	E: serde::de::Error,
+{
+	let mut value = T::default();
+	let bytes = value.as_mut();
+	if decoded.len() != bytes.len() {
+		return Err(E::invalid_length(decoded.len(), &format!("{} bytes", bytes.len()).as_str()))
+	}
+	bytes.copy_from_slice(decoded);
+	Ok(value)
+}
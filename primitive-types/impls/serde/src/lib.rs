@@ -22,6 +22,15 @@ pub use serde;
 #[doc(hidden)]
 pub mod serialize;
 
+#[cfg(feature = "bs58")]
+pub mod base58;
+#[cfg(feature = "base64")]
+pub mod base64;
+#[cfg(feature = "bech32")]
+pub mod bech32;
+#[cfg(any(feature = "bs58", feature = "base64", feature = "bech32"))]
+mod encoding;
+
 /// Add Serde serialization support to an integer created by `construct_uint!`.
 #[macro_export]
 macro_rules! impl_uint_serde {
@@ -85,9 +94,15 @@ macro_rules! impl_fixed_hash_serde {
 }
 
 /// Add Serde serialization support to a fixed-sized hash type created by `construct_fixed_hash!` and which do not need 0x prefix.
+///
+/// An optional third argument, a function from `&$name` to a `Result` with a displayable
+/// error, rejects values that decode to the right length but are otherwise invalid.
 #[macro_export]
 macro_rules! impl_fixed_hash_serde_no_0x_prefix {
 	($name: ident, $len: expr) => {
+		$crate::impl_fixed_hash_serde_no_0x_prefix!($name, $len, |_: &$name| Ok::<(), ::core::convert::Infallible>(()));
+	};
+	($name: ident, $len: expr, $validate: expr) => {
 		impl $crate::serde::Serialize for $name {
 			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 			where
@@ -108,7 +123,9 @@ macro_rules! impl_fixed_hash_serde_no_0x_prefix {
 					deserializer,
 					$crate::serialize::ExpectedLen::Exact(&mut bytes),
 				)?;
-				Ok($name(bytes))
+				let hash = $name(bytes);
+				($validate)(&hash).map_err($crate::serde::de::Error::custom)?;
+				Ok(hash)
 			}
 		}
 	};
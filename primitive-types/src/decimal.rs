@@ -0,0 +1,390 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Fixed-point decimal amounts backed by `U256`.
+
+use super::{U256, U512};
+use core::{
+	convert::TryFrom,
+	fmt::{self, Write},
+	str::FromStr,
+};
+
+/// Most fractional digits `Display` will print, larger precisions are capped.
+const MAX_PRECISION: usize = 128;
+
+/// Up to 78 integer digits, the decimal point and the fractional digits.
+const BUFFER_LEN: usize = 78 + 1 + MAX_PRECISION;
+
+/// How to round when a value has more fractional digits than requested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+	/// Round towards zero, dropping the extra digits.
+	Down,
+	/// Round away from zero.
+	Up,
+	/// Round to the nearest value, ties away from zero.
+	HalfUp,
+	/// Round to the nearest value, ties to the even neighbour.
+	HalfEven,
+}
+
+/// Error parsing a `Decimal` from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FromDecimalStrErr {
+	/// Empty input, or a character other than a digit and a single decimal point.
+	InvalidCharacter,
+	/// More significant fractional digits than the type can hold.
+	TooManyDecimals,
+	/// The value does not fit in the underlying `U256`.
+	Overflow,
+}
+
+impl fmt::Display for FromDecimalStrErr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			FromDecimalStrErr::InvalidCharacter => "a character is not a decimal digit or point",
+			FromDecimalStrErr::TooManyDecimals => "too many fractional digits",
+			FromDecimalStrErr::Overflow => "number is too large",
+		})
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromDecimalStrErr {}
+
+/// Unsigned fixed-point decimal with `DECIMALS` fractional digits, stored as a
+/// `U256` count of the smallest unit.
+///
+/// `Decimal<18>` holds token balances the way they are stored on chain: the raw
+/// value `1_500_000_000_000_000_000` is the amount `1.5`. `DECIMALS` must be at
+/// most 77, so that one whole unit fits in a `U256`.
+///
+/// # Example
+///
+/// ```
+/// use primitive_types::{Decimal, Rounding, U256};
+///
+/// let balance: Decimal<18> = "1.5".parse().unwrap();
+/// assert_eq!(balance.into_raw(), U256::exp10(18) * 3 / 2);
+///
+/// let fee = Decimal::<18>::from_raw(U256::from(21_000u64) * U256::exp10(9));
+/// assert_eq!(fee.to_string(), "0.000021");
+///
+/// let third = balance.checked_div(Decimal::from_integer(U256::from(3)).unwrap()).unwrap();
+/// assert_eq!(format!("{:.4}", third), "0.5000");
+/// assert_eq!(third.display(0, Rounding::Up).to_string(), "1");
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal<const DECIMALS: u32>(U256);
+
+impl<const DECIMALS: u32> Decimal<DECIMALS> {
+	/// The raw value of one whole unit, `10^DECIMALS`.
+	pub const UNIT: U256 = {
+		assert!(DECIMALS <= 77, "10^DECIMALS must fit in a U256");
+		let mut unit = U256::one();
+		let mut i = 0;
+		while i < DECIMALS {
+			unit = unit.const_mul(U256::from_u64(10));
+			i += 1;
+		}
+		unit
+	};
+
+	/// Zero.
+	pub const fn zero() -> Self {
+		Decimal(U256::zero())
+	}
+
+	/// One whole unit.
+	pub const fn one() -> Self {
+		Decimal(Self::UNIT)
+	}
+
+	/// The largest representable amount.
+	pub const fn max_value() -> Self {
+		Decimal(U256::MAX)
+	}
+
+	/// Wraps a raw count of the smallest unit.
+	pub const fn from_raw(raw: U256) -> Self {
+		Decimal(raw)
+	}
+
+	/// The raw count of the smallest unit.
+	pub const fn into_raw(self) -> U256 {
+		self.0
+	}
+
+	/// The number of fractional digits.
+	pub const fn decimals(&self) -> u32 {
+		DECIMALS
+	}
+
+	/// Converts a whole number, returning `None` if it does not fit.
+	pub fn from_integer(value: U256) -> Option<Self> {
+		value.checked_mul(Self::UNIT).map(Decimal)
+	}
+
+	/// Whether this is zero.
+	pub fn is_zero(&self) -> bool {
+		self.0.is_zero()
+	}
+
+	/// The whole part of the amount.
+	pub fn trunc(&self) -> U256 {
+		self.0 / Self::UNIT
+	}
+
+	/// The fractional part of the amount, in the smallest unit.
+	pub fn fract(&self) -> U256 {
+		self.0 % Self::UNIT
+	}
+
+	/// Checked addition. Returns `None` if overflow occurred.
+	pub fn checked_add(self, other: Self) -> Option<Self> {
+		self.0.checked_add(other.0).map(Decimal)
+	}
+
+	/// Checked subtraction. Returns `None` if the result would be negative.
+	pub fn checked_sub(self, other: Self) -> Option<Self> {
+		self.0.checked_sub(other.0).map(Decimal)
+	}
+
+	/// Checked multiplication, truncating digits beyond `DECIMALS`. Returns
+	/// `None` if overflow occurred.
+	pub fn checked_mul(self, other: Self) -> Option<Self> {
+		let product = self.0.full_mul(other.0) / U512::from(Self::UNIT);
+		U256::try_from(product).ok().map(Decimal)
+	}
+
+	/// Checked division, truncating digits beyond `DECIMALS`. Returns `None`
+	/// if `other` is zero or overflow occurred.
+	pub fn checked_div(self, other: Self) -> Option<Self> {
+		if other.is_zero() {
+			return None
+		}
+		let quotient = self.0.full_mul(Self::UNIT) / U512::from(other.0);
+		U256::try_from(quotient).ok().map(Decimal)
+	}
+
+	/// Converts to a different number of decimals, rounding if digits are
+	/// dropped. Returns `None` if the result does not fit.
+	pub fn rescale<const TO: u32>(self, rounding: Rounding) -> Option<Decimal<TO>> {
+		if TO >= DECIMALS {
+			self.0.checked_mul(U256::exp10((TO - DECIMALS) as usize)).map(Decimal)
+		} else {
+			Some(Decimal(div_rounded(self.0, U256::exp10((DECIMALS - TO) as usize), rounding)))
+		}
+	}
+
+	/// Rounds to `precision` fractional digits. Returns `None` if rounding up
+	/// overflows.
+	pub fn round(self, precision: u32, rounding: Rounding) -> Option<Self> {
+		if precision >= DECIMALS {
+			return Some(self)
+		}
+		let factor = U256::exp10((DECIMALS - precision) as usize);
+		div_rounded(self.0, factor, rounding).checked_mul(factor).map(Decimal)
+	}
+
+	/// Displays the amount with exactly `precision` fractional digits, rounding
+	/// as requested.
+	pub fn display(&self, precision: usize, rounding: Rounding) -> DecimalDisplay<DECIMALS> {
+		DecimalDisplay { value: *self, precision, rounding }
+	}
+
+	fn fmt_with(&self, f: &mut fmt::Formatter, precision: Option<usize>, rounding: Rounding) -> fmt::Result {
+		let decimals = DECIMALS as usize;
+		let (int, frac, frac_digits) = match precision {
+			Some(precision) if precision < decimals => {
+				let scaled = div_rounded(self.0, U256::exp10(decimals - precision), rounding);
+				let unit = U256::exp10(precision);
+				(scaled / unit, scaled % unit, precision)
+			},
+			_ => (self.trunc(), self.fract(), decimals),
+		};
+
+		let mut buf = Buffer { bytes: [0; BUFFER_LEN], len: 0 };
+		write!(buf, "{}", int)?;
+		if frac_digits > 0 {
+			buf.push(b'.');
+			// `U256` ignores the width when formatting zero
+			if frac.is_zero() {
+				(0..frac_digits).for_each(|_| buf.push(b'0'));
+			} else {
+				write!(buf, "{:0width$}", frac, width = frac_digits)?;
+			}
+		}
+		match precision {
+			Some(precision) => {
+				if decimals == 0 && precision > 0 {
+					buf.push(b'.');
+				}
+				// digits past `DECIMALS` are always zero
+				for _ in decimals..precision.min(MAX_PRECISION) {
+					buf.push(b'0');
+				}
+			},
+			None if frac_digits > 0 => {
+				while buf.bytes[buf.len - 1] == b'0' {
+					buf.len -= 1;
+				}
+				if buf.bytes[buf.len - 1] == b'.' {
+					buf.len -= 1;
+				}
+			},
+			None => {},
+		}
+
+		// sequence of `'0'..'9'` and `'.'` chars is guaranteed to be a valid UTF8 string
+		let s = unsafe { core::str::from_utf8_unchecked(&buf.bytes[..buf.len]) };
+		f.pad_integral(true, "", s)
+	}
+}
+
+/// Formats with all significant fractional digits, or with `{:.N}` exactly `N`
+/// of them, rounding half to even.
+impl<const DECIMALS: u32> fmt::Display for Decimal<DECIMALS> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.fmt_with(f, f.precision(), Rounding::HalfEven)
+	}
+}
+
+impl<const DECIMALS: u32> fmt::Debug for Decimal<DECIMALS> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(self, f)
+	}
+}
+
+impl<const DECIMALS: u32> FromStr for Decimal<DECIMALS> {
+	type Err = FromDecimalStrErr;
+
+	/// Parses `"1"`, `"1.5"`, `"1."` or `".5"`. Trailing fractional zeros
+	/// beyond `DECIMALS` are accepted.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (int, frac) = match s.find('.') {
+			Some(dot) => (&s[..dot], &s[dot + 1..]),
+			None => (s, ""),
+		};
+		if int.is_empty() && frac.is_empty() {
+			return Err(FromDecimalStrErr::InvalidCharacter)
+		}
+
+		let parse = |digits: &str| {
+			if digits.is_empty() {
+				return Ok(U256::zero())
+			}
+			U256::from_dec_str(digits).map_err(|e| match e {
+				uint::FromDecStrErr::InvalidCharacter => FromDecimalStrErr::InvalidCharacter,
+				uint::FromDecStrErr::InvalidLength => FromDecimalStrErr::Overflow,
+			})
+		};
+
+		let int = parse(int)?;
+		let frac_digits = frac.trim_end_matches('0');
+		if frac_digits.len() > DECIMALS as usize {
+			return Err(FromDecimalStrErr::TooManyDecimals)
+		}
+		let frac = parse(frac_digits)?;
+		let frac = frac * U256::exp10(DECIMALS as usize - frac_digits.len());
+
+		int.checked_mul(Self::UNIT)
+			.and_then(|int| int.checked_add(frac))
+			.map(Decimal)
+			.ok_or(FromDecimalStrErr::Overflow)
+	}
+}
+
+/// Formats a `Decimal` with a fixed precision and rounding mode, see
+/// [`Decimal::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct DecimalDisplay<const DECIMALS: u32> {
+	value: Decimal<DECIMALS>,
+	precision: usize,
+	rounding: Rounding,
+}
+
+impl<const DECIMALS: u32> fmt::Display for DecimalDisplay<DECIMALS> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.value.fmt_with(f, Some(self.precision), self.rounding)
+	}
+}
+
+// Fixed-size output buffer, so formatting needs no allocation.
+struct Buffer {
+	bytes: [u8; BUFFER_LEN],
+	len: usize,
+}
+
+impl Buffer {
+	fn push(&mut self, byte: u8) {
+		self.bytes[self.len] = byte;
+		self.len += 1;
+	}
+}
+
+impl fmt::Write for Buffer {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		let end = self.len + s.len();
+		if end > BUFFER_LEN {
+			return Err(fmt::Error)
+		}
+		self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+		self.len = end;
+		Ok(())
+	}
+}
+
+// Divides `n` by a non-zero `d`, rounding the quotient as requested.
+fn div_rounded(n: U256, d: U256, rounding: Rounding) -> U256 {
+	let (q, r) = n.div_mod(d);
+	let round_up = !r.is_zero() &&
+		match rounding {
+			Rounding::Down => false,
+			Rounding::Up => true,
+			// compare `2 * r` against `d` without overflowing
+			Rounding::HalfUp => r >= d - r,
+			Rounding::HalfEven => r > d - r || (r == d - r && q.bit(0)),
+		};
+	// `q` can only be `U256::MAX` when `d` is one, which leaves no remainder
+	q + round_up as u64
+}
+
+#[cfg(feature = "impl-serde")]
+mod serde {
+	use super::*;
+	use impl_serde::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+	/// Serializes as a decimal string such as `"1.5"`.
+	impl<const DECIMALS: u32> Serialize for Decimal<DECIMALS> {
+		fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+			serializer.collect_str(self)
+		}
+	}
+
+	impl<'de, const DECIMALS: u32> Deserialize<'de> for Decimal<DECIMALS> {
+		fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+			struct Visitor<const DECIMALS: u32>;
+
+			impl<'de, const DECIMALS: u32> de::Visitor<'de> for Visitor<DECIMALS> {
+				type Value = Decimal<DECIMALS>;
+
+				fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+					write!(formatter, "a decimal string with at most {} fractional digits", DECIMALS)
+				}
+
+				fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+					v.parse().map_err(|e| E::custom(format_args!("invalid decimal {:?}: {}", v, e)))
+				}
+			}
+
+			deserializer.deserialize_str(Visitor)
+		}
+	}
+}
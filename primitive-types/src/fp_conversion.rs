@@ -1,43 +1,151 @@
-use super::U256;
-
-impl U256 {
-	/// Lossy saturating conversion from a `f64` to a `U256`. Like for floating point to
-	/// primitive integer type conversions, this truncates fractional parts.
-	///
-	/// The conversion follows the same rules as converting `f64` to other
-	/// primitive integer types. Namely, the conversion of `value: f64` behaves as
-	/// follows:
-	/// - `NaN` => `0`
-	/// - `(-∞, 0]` => `0`
-	/// - `(0, u256::MAX]` => `value as u256`
-	/// - `(u256::MAX, +∞)` => `u256::MAX`
-	pub fn from_f64_lossy(value: f64) -> U256 {
-		if value >= 1.0 {
-			let bits = value.to_bits();
-			// NOTE: Don't consider the sign or check that the subtraction will
-			//   underflow since we already checked that the value is greater
-			//   than 1.0.
-			let exponent = ((bits >> 52) & 0x7ff) - 1023;
-			let mantissa = (bits & 0x0f_ffff_ffff_ffff) | 0x10_0000_0000_0000;
-			if exponent <= 52 {
-				U256::from(mantissa >> (52 - exponent))
-			} else if exponent >= 256 {
-				U256::MAX
-			} else {
-				U256::from(mantissa) << U256::from(exponent - 52)
-			}
-		} else {
-			0.into()
-		}
-	}
+use super::{U1024, U128, U1368, U256, U448, U456, U512};
+use core::{convert::TryFrom, fmt};
+
+/// Error for the exact conversion from a float into a uint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromFloatError {
+	/// The value is NaN.
+	NotANumber,
+	/// The value is negative.
+	Negative,
+	/// The value has a fractional part.
+	Fractional,
+	/// The value does not fit, including infinity.
+	Overflow,
+}
 
-	/// Lossy conversion of `U256` to `f64`.
-	pub fn to_f64_lossy(self) -> f64 {
-		let (res, factor) = match self {
-			U256([_, _, 0, 0]) => (self, 1.0),
-			U256([_, _, _, 0]) => (self >> 64, 2.0f64.powi(64)),
-			U256([_, _, _, _]) => (self >> 128, 2.0f64.powi(128)),
-		};
-		(res.low_u128() as f64) * factor
+impl fmt::Display for FromFloatError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			FromFloatError::NotANumber => "cannot convert NaN to an integer",
+			FromFloatError::Negative => "cannot convert a negative number to an unsigned integer",
+			FromFloatError::Fractional => "cannot convert a number with a fractional part to an integer",
+			FromFloatError::Overflow => "number too large to fit in target type",
+		})
 	}
 }
+
+impl std::error::Error for FromFloatError {}
+
+macro_rules! impl_fp_conversion {
+	($name:ident, $n_words:expr) => {
+		impl $name {
+			const FP_BITS: u64 = $n_words * 64;
+
+			/// Lossy saturating conversion from a `f64` to a uint. Like for floating point to
+			/// primitive integer type conversions, this truncates fractional parts.
+			///
+			/// The conversion follows the same rules as converting `f64` to other
+			/// primitive integer types. Namely, the conversion of `value: f64` behaves as
+			/// follows:
+			/// - `NaN` => `0`
+			/// - `(-∞, 0]` => `0`
+			/// - `(0, MAX]` => `value as uint`
+			/// - `(MAX, +∞)` => `MAX`
+			pub fn from_f64_lossy(value: f64) -> $name {
+				if value >= 1.0 {
+					let bits = value.to_bits();
+					// NOTE: Don't consider the sign or check that the subtraction will
+					//   underflow since we already checked that the value is greater
+					//   than 1.0.
+					let exponent = ((bits >> 52) & 0x7ff) - 1023;
+					let mantissa = (bits & 0x0f_ffff_ffff_ffff) | 0x10_0000_0000_0000;
+					if exponent <= 52 {
+						$name::from(mantissa >> (52 - exponent))
+					} else if exponent >= Self::FP_BITS || value.is_infinite() {
+						$name::MAX
+					} else {
+						$name::from(mantissa) << (exponent - 52) as usize
+					}
+				} else {
+					0.into()
+				}
+			}
+
+			/// Lossy saturating conversion from a `f32`, following the same rules as
+			/// [`Self::from_f64_lossy`].
+			pub fn from_f32_lossy(value: f32) -> $name {
+				Self::from_f64_lossy(f64::from(value))
+			}
+
+			/// Conversion to the nearest `f64`, rounding half to even like `u128 as f64`.
+			/// Values above `f64::MAX` become infinity.
+			pub fn to_f64(self) -> f64 {
+				let (top, shift) = self.float_parts();
+				if shift > 1023 {
+					return f64::INFINITY
+				}
+				// `top as f64` is the only rounding step, multiplying by a power of two is exact
+				(top as f64) * f64::from_bits((1023 + shift) << 52)
+			}
+
+			/// Conversion to the nearest `f32`, rounding half to even like `u128 as f32`.
+			/// Values above `f32::MAX` become infinity.
+			pub fn to_f32(self) -> f32 {
+				let (top, shift) = self.float_parts();
+				if shift > 127 {
+					return f32::INFINITY
+				}
+				(top as f32) * f32::from_bits((127 + shift as u32) << 23)
+			}
+
+			/// Lossy conversion to `f64`, same as [`Self::to_f64`].
+			pub fn to_f64_lossy(self) -> f64 {
+				self.to_f64()
+			}
+
+			// Splits `self` into its top 64 bits and the number of bits shifted out, with
+			// the lowest bit set if any shifted out bit was. That "sticky" bit lies below
+			// the rounding position of both `f32` and `f64`, so converting the top word
+			// rounds exactly like converting the whole number would.
+			fn float_parts(&self) -> (u64, u64) {
+				let bits = self.bits();
+				if bits <= 64 {
+					return (self.low_u64(), 0)
+				}
+				let shift = bits - 64;
+				let top = (*self >> shift).low_u64();
+				let sticky = self.trailing_zeros() < shift as u32;
+				(top | sticky as u64, shift as u64)
+			}
+		}
+
+		/// Exact conversion, failing on NaN, negative, fractional and too large values.
+		impl TryFrom<f64> for $name {
+			type Error = FromFloatError;
+
+			fn try_from(value: f64) -> Result<$name, FromFloatError> {
+				if value.is_nan() {
+					return Err(FromFloatError::NotANumber)
+				}
+				if value < 0.0 {
+					return Err(FromFloatError::Negative)
+				}
+				if value.is_infinite() || (value >= 1.0 && ((value.to_bits() >> 52) & 0x7ff) - 1023 >= Self::FP_BITS) {
+					return Err(FromFloatError::Overflow)
+				}
+				if value.fract() != 0.0 {
+					return Err(FromFloatError::Fractional)
+				}
+				Ok(Self::from_f64_lossy(value))
+			}
+		}
+
+		/// Exact conversion, failing on NaN, negative, fractional and too large values.
+		impl TryFrom<f32> for $name {
+			type Error = FromFloatError;
+
+			fn try_from(value: f32) -> Result<$name, FromFloatError> {
+				$name::try_from(f64::from(value))
+			}
+		}
+	};
+}
+
+impl_fp_conversion!(U128, 2);
+impl_fp_conversion!(U256, 4);
+impl_fp_conversion!(U448, 7);
+impl_fp_conversion!(U456, 8);
+impl_fp_conversion!(U512, 8);
+impl_fp_conversion!(U1024, 16);
+impl_fp_conversion!(U1368, 22);
@@ -0,0 +1,169 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! ICAN addresses.
+//!
+//! A Core address is an `H176`: one network prefix byte, one byte holding two
+//! decimal check digits, and the 20-byte account body. Written out it is 44 hex
+//! characters, for example `cb57bbbb54cdf60fa666fd741be78f794d4608d67109`. The
+//! check digits follow IBAN: the hex body, the hex prefix and `00` are read as a
+//! base 36 number, and the check digits are 98 minus its remainder modulo 97.
+
+use super::{H160, H176};
+use core::fmt;
+
+/// Network an ICAN address belongs to, given by its first byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IcanNetwork {
+	/// Mainnet, prefix `cb`.
+	Mainnet,
+	/// Testnet, prefix `ab`.
+	Testnet,
+	/// Private networks, prefix `ce`.
+	Private,
+}
+
+impl IcanNetwork {
+	/// The address prefix byte.
+	pub const fn prefix(self) -> u8 {
+		match self {
+			IcanNetwork::Mainnet => 0xcb,
+			IcanNetwork::Testnet => 0xab,
+			IcanNetwork::Private => 0xce,
+		}
+	}
+
+	/// The network with the given address prefix byte.
+	pub const fn from_prefix(prefix: u8) -> Option<Self> {
+		match prefix {
+			0xcb => Some(IcanNetwork::Mainnet),
+			0xab => Some(IcanNetwork::Testnet),
+			0xce => Some(IcanNetwork::Private),
+			_ => None,
+		}
+	}
+}
+
+/// Error validating an ICAN address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IcanError {
+	/// The string is not 44 characters long.
+	InvalidLength,
+	/// The string contains a character that is not a hex digit.
+	InvalidHex,
+	/// The prefix is not a known network.
+	UnknownNetwork,
+	/// The check digits do not match the rest of the address.
+	InvalidChecksum,
+}
+
+impl fmt::Display for IcanError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			IcanError::InvalidLength => "ICAN address must be 44 hex characters",
+			IcanError::InvalidHex => "ICAN address contains a non-hex character",
+			IcanError::UnknownNetwork => "ICAN address has an unknown network prefix",
+			IcanError::InvalidChecksum => "ICAN address checksum mismatch",
+		})
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IcanError {}
+
+impl H176 {
+	/// Builds the address of `body` on `network`, computing the check digits.
+	pub fn from_ican_parts(network: IcanNetwork, body: &H160) -> Self {
+		let mut address = [0u8; 22];
+		address[0] = network.prefix();
+		let checksum = Self::ican_checksum(network, body);
+		address[1] = ((checksum / 10) << 4) | (checksum % 10);
+		address[2..].copy_from_slice(body.as_bytes());
+		H176(address)
+	}
+
+	/// Computes the check digits, in `2..=98`, of `body` on `network`.
+	pub const fn ican_checksum(network: IcanNetwork, body: &H160) -> u8 {
+		let mut remainder = 0u32;
+		let mut i = 0;
+		while i < 22 {
+			let byte = match i {
+				20 => network.prefix(),
+				21 => 0,
+				_ => body.0[i],
+			};
+			remainder = ican_digit(remainder, byte >> 4);
+			remainder = ican_digit(remainder, byte & 0xf);
+			i += 1;
+		}
+		(98 - remainder) as u8
+	}
+
+	/// Checks an address at compile time, panicking if it is not valid.
+	#[doc(hidden)]
+	pub const fn from_ican_literal(bytes: [u8; 22]) -> Self {
+		let network = match IcanNetwork::from_prefix(bytes[0]) {
+			Some(network) => network,
+			None => panic!("ICAN address has an unknown network prefix"),
+		};
+		let mut body = [0u8; 20];
+		let mut i = 0;
+		while i < 20 {
+			body[i] = bytes[i + 2];
+			i += 1;
+		}
+		let checksum = Self::ican_checksum(network, &H160(body));
+		if bytes[1] != ((checksum / 10) << 4) | (checksum % 10) {
+			panic!("ICAN address checksum mismatch");
+		}
+		H176(bytes)
+	}
+
+	/// Parses and validates an ICAN address, with or without a `0x` prefix.
+	pub fn from_ican_str(s: &str) -> Result<Self, IcanError> {
+		let s = s.strip_prefix("0x").unwrap_or(s).as_bytes();
+		if s.len() != 44 {
+			return Err(IcanError::InvalidLength)
+		}
+		let mut address = [0u8; 22];
+		for (byte, pair) in address.iter_mut().zip(s.chunks(2)) {
+			let hi = (pair[0] as char).to_digit(16).ok_or(IcanError::InvalidHex)?;
+			let lo = (pair[1] as char).to_digit(16).ok_or(IcanError::InvalidHex)?;
+			*byte = ((hi << 4) | lo) as u8;
+		}
+		let address = H176(address);
+		address.validate_ican()?;
+		Ok(address)
+	}
+
+	/// Checks that the prefix is a known network and the check digits match.
+	pub fn validate_ican(&self) -> Result<(), IcanError> {
+		let network = self.ican_network().ok_or(IcanError::UnknownNetwork)?;
+		if *self != Self::from_ican_parts(network, &self.ican_body()) {
+			return Err(IcanError::InvalidChecksum)
+		}
+		Ok(())
+	}
+
+	/// The network given by the prefix byte, if known.
+	pub fn ican_network(&self) -> Option<IcanNetwork> {
+		IcanNetwork::from_prefix(self.0[0])
+	}
+
+	/// The 20-byte account body.
+	pub fn ican_body(&self) -> H160 {
+		H160::from_slice(&self.0[2..])
+	}
+}
+
+// Appends one hex digit to the running remainder. Letters `a` to `f` read as
+// the two digit numbers 10 to 15.
+const fn ican_digit(remainder: u32, nibble: u8) -> u32 {
+	let scale = if nibble < 10 { 10 } else { 100 };
+	(remainder * scale + nibble as u32) % 97
+}
@@ -8,12 +8,15 @@
 
 //! Primitive types shared by Substrate and Parity Ethereum.
 //!
-//! Those are uint types `U128`, `U256` and `U512`, and fixed hash types `H160`,
-//! `H256` and `H512`, with optional serde serialization, parity-scale-codec and
-//! rlp encoding.
+//! Those are uint types `U128`, `U256` and `U512`, signed int types `I128`,
+//! `I256` and `I512`, and fixed hash types `H160`, `H256` and `H512`, with
+//! optional serde serialization, parity-scale-codec and rlp encoding, and the
+//! `Decimal` fixed-point amount over `U256`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod decimal;
+mod ican;
 #[cfg(feature = "fp-conversion")]
 mod fp_conversion;
 
@@ -21,7 +24,12 @@ use core::convert::TryFrom;
 use fixed_hash::{construct_fixed_hash, impl_fixed_hash_conversions};
 #[cfg(feature = "scale-info")]
 use scale_info_crate::TypeInfo;
-use uint::{construct_uint, uint_full_mul_reg};
+use uint::{construct_int, construct_uint, uint_full_mul_reg};
+
+pub use decimal::{Decimal, DecimalDisplay, FromDecimalStrErr, Rounding};
+pub use ican::{IcanError, IcanNetwork};
+#[cfg(feature = "fp-conversion")]
+pub use fp_conversion::FromFloatError;
 
 /// Error type for conversion.
 #[derive(Debug, PartialEq, Eq)]
@@ -59,12 +67,36 @@ construct_uint! {
 	pub struct U512(8);
 }
 
+construct_uint! {
+	/// 1024-bits unsigned integer.
+	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
+	pub struct U1024(16);
+}
+
 construct_uint! {
 	/// 1368-bits unsigned integer.
 	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
 	pub struct U1368(22);
 }
 
+construct_int! {
+	/// 128-bit signed integer.
+	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
+	pub struct I128(U128);
+}
+
+construct_int! {
+	/// 256-bit signed integer.
+	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
+	pub struct I256(U256);
+}
+
+construct_int! {
+	/// 512-bit signed integer.
+	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
+	pub struct I512(U512);
+}
+
 construct_fixed_hash! {
 	/// Fixed-size uninterpreted hash type with 16 bytes (128 bits) size.
 	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
@@ -78,7 +110,7 @@ construct_fixed_hash! {
 }
 
 construct_fixed_hash! {
-	/// Fixed-size uninterpreted hash type with 20 bytes (160 bits) size.
+	/// Fixed-size uninterpreted hash type with 22 bytes (176 bits) size.
 	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
 	pub struct H176(22, true);
 }
@@ -101,7 +133,7 @@ construct_fixed_hash! {
 }
 
 construct_fixed_hash! {
-	/// Fixed-size uninterpreted hash type with 48 bytes (384 bits) size.
+	/// Fixed-size uninterpreted hash type with 57 bytes (456 bits) size.
 	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
 	pub struct H456(57);
 }
@@ -118,7 +150,7 @@ construct_fixed_hash! {
 }
 
 construct_fixed_hash! {
-	/// Fixed-size uninterpreted hash type with 96 bytes (768 bits) size.
+	/// Fixed-size uninterpreted hash type with 171 bytes (1368 bits) size.
 	#[cfg_attr(feature = "scale-info", derive(TypeInfo))]
 	pub struct H1368(171);
 }
@@ -130,8 +162,11 @@ mod num_traits {
 
 	impl_uint_num_traits!(U128, 2);
 	impl_uint_num_traits!(U256, 4);
+	impl_uint_num_traits!(U448, 7);
 	impl_uint_num_traits!(U456, 8);
 	impl_uint_num_traits!(U512, 8);
+	impl_uint_num_traits!(U1024, 16);
+	impl_uint_num_traits!(U1368, 22);
 }
 
 #[cfg(feature = "impl-serde")]
@@ -141,15 +176,18 @@ mod serde {
 
 	impl_uint_serde!(U128, 2);
 	impl_uint_serde!(U256, 4);
+	impl_uint_serde!(U448, 7);
 	impl_uint_serde!(U456, 8);
 	impl_uint_serde!(U512, 8);
+	impl_uint_serde!(U1024, 16);
 	impl_uint_serde!(U1368, 22);
 
 	impl_fixed_hash_serde!(H128, 16);
 	impl_fixed_hash_serde!(H160, 20);
-	impl_fixed_hash_serde_no_0x_prefix!(H176, 22);
+	impl_fixed_hash_serde_no_0x_prefix!(H176, 22, H176::validate_ican);
 	impl_fixed_hash_serde!(H256, 32);
 	impl_fixed_hash_serde!(H384, 48);
+	impl_fixed_hash_serde!(H448, 56);
 	impl_fixed_hash_serde!(H456, 57);
 	impl_fixed_hash_serde!(H512, 64);
 	impl_fixed_hash_serde!(H768, 96);
@@ -163,8 +201,10 @@ mod codec {
 
 	impl_uint_codec!(U128, 2);
 	impl_uint_codec!(U256, 4);
+	impl_uint_codec!(U448, 7);
 	impl_uint_codec!(U456, 8);
 	impl_uint_codec!(U512, 8);
+	impl_uint_codec!(U1024, 16);
 	impl_uint_codec!(U1368, 22);
 
 	impl_fixed_hash_codec!(H128, 16);
@@ -172,6 +212,7 @@ mod codec {
 	impl_fixed_hash_codec!(H176, 22);
 	impl_fixed_hash_codec!(H256, 32);
 	impl_fixed_hash_codec!(H384, 48);
+	impl_fixed_hash_codec!(H448, 56);
 	impl_fixed_hash_codec!(H456, 57);
 	impl_fixed_hash_codec!(H512, 64);
 	impl_fixed_hash_codec!(H768, 96);
@@ -185,8 +226,10 @@ mod rlp {
 
 	impl_uint_rlp!(U128, 2);
 	impl_uint_rlp!(U256, 4);
+	impl_uint_rlp!(U448, 7);
 	impl_uint_rlp!(U456, 8);
 	impl_uint_rlp!(U512, 8);
+	impl_uint_rlp!(U1024, 16);
 	impl_uint_rlp!(U1368, 22);
 
 	impl_fixed_hash_rlp!(H128, 16);
@@ -194,6 +237,7 @@ mod rlp {
 	impl_fixed_hash_rlp!(H176, 22);
 	impl_fixed_hash_rlp!(H256, 32);
 	impl_fixed_hash_rlp!(H384, 48);
+	impl_fixed_hash_rlp!(H448, 56);
 	impl_fixed_hash_rlp!(H456, 57);
 	impl_fixed_hash_rlp!(H512, 64);
 	impl_fixed_hash_rlp!(H768, 96);
@@ -203,128 +247,90 @@ mod rlp {
 impl_fixed_hash_conversions!(H256, H160);
 impl_fixed_hash_conversions!(H256, H176);
 
-impl U128 {
-	/// Multiplies two 128-bit integers to produce full 256-bit integer.
-	/// Overflow is not possible.
-	#[inline(always)]
-	pub fn full_mul(self, other: U128) -> U256 {
-		U256(uint_full_mul_reg!(U128, 2, self, other))
-	}
-}
-
-impl U256 {
-	/// Multiplies two 256-bit integers to produce full 512-bit integer.
-	/// Overflow is not possible.
-	#[inline(always)]
-	pub fn full_mul(self, other: U256) -> U512 {
-		U512(uint_full_mul_reg!(U256, 4, self, other))
-	}
-}
-
-impl From<U256> for U512 {
-	fn from(value: U256) -> U512 {
-		let U256(ref arr) = value;
-		let mut ret = [0; 8];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		ret[2] = arr[2];
-		ret[3] = arr[3];
-		U512(ret)
-	}
-}
-
-impl TryFrom<U256> for U128 {
-	type Error = Error;
-
-	fn try_from(value: U256) -> Result<U128, Error> {
-		let U256(ref arr) = value;
-		if arr[2] | arr[3] != 0 {
-			return Err(Error::Overflow)
+/// Implements the lossless `From` conversion from a narrower uint into a wider
+/// one, and the checked `TryFrom` conversion back.
+macro_rules! impl_uint_widening_conversions {
+	($narrow:ident, $narrow_words:expr, $wide:ident, $wide_words:expr) => {
+		impl From<$narrow> for $wide {
+			fn from(value: $narrow) -> $wide {
+				let mut ret = [0; $wide_words];
+				ret[..$narrow_words].copy_from_slice(&value.0);
+				$wide(ret)
+			}
 		}
-		let mut ret = [0; 2];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		Ok(U128(ret))
-	}
-}
-
-impl TryFrom<U512> for U256 {
-	type Error = Error;
 
-	fn try_from(value: U512) -> Result<U256, Error> {
-		let U512(ref arr) = value;
-		if arr[4] | arr[5] | arr[6] | arr[7] != 0 {
-			return Err(Error::Overflow)
+		impl<'a> From<&'a $narrow> for $wide {
+			fn from(value: &'a $narrow) -> $wide {
+				(*value).into()
+			}
 		}
-		let mut ret = [0; 4];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		ret[2] = arr[2];
-		ret[3] = arr[3];
-		Ok(U256(ret))
-	}
-}
-
-impl TryFrom<U512> for U128 {
-	type Error = Error;
 
-	fn try_from(value: U512) -> Result<U128, Error> {
-		let U512(ref arr) = value;
-		if arr[2] | arr[3] | arr[4] | arr[5] | arr[6] | arr[7] != 0 {
-			return Err(Error::Overflow)
+		impl TryFrom<$wide> for $narrow {
+			type Error = Error;
+
+			fn try_from(value: $wide) -> Result<$narrow, Error> {
+				if value.0[$narrow_words..].iter().any(|&word| word != 0) {
+					return Err(Error::Overflow)
+				}
+				let mut ret = [0; $narrow_words];
+				ret.copy_from_slice(&value.0[..$narrow_words]);
+				Ok($narrow(ret))
+			}
 		}
-		let mut ret = [0; 2];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		Ok(U128(ret))
-	}
-}
 
-impl From<U128> for U512 {
-	fn from(value: U128) -> U512 {
-		let U128(ref arr) = value;
-		let mut ret = [0; 8];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		U512(ret)
-	}
-}
+		impl<'a> TryFrom<&'a $wide> for $narrow {
+			type Error = Error;
 
-impl From<U128> for U256 {
-	fn from(value: U128) -> U256 {
-		let U128(ref arr) = value;
-		let mut ret = [0; 4];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		U256(ret)
-	}
-}
-
-impl<'a> From<&'a U256> for U512 {
-	fn from(value: &'a U256) -> U512 {
-		let U256(ref arr) = *value;
-		let mut ret = [0; 8];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		ret[2] = arr[2];
-		ret[3] = arr[3];
-		U512(ret)
-	}
+			fn try_from(value: &'a $wide) -> Result<$narrow, Error> {
+				TryFrom::try_from(*value)
+			}
+		}
+	};
 }
 
-impl<'a> TryFrom<&'a U512> for U256 {
-	type Error = Error;
-
-	fn try_from(value: &'a U512) -> Result<U256, Error> {
-		let U512(ref arr) = *value;
-		if arr[4] | arr[5] | arr[6] | arr[7] != 0 {
-			return Err(Error::Overflow)
+/// Implements `full_mul`, producing the double-width product in a wider uint.
+macro_rules! impl_uint_full_mul {
+	($name:ident, $n_words:tt, $wide:ident, $wide_words:expr, $doc:expr) => {
+		impl $name {
+			#[doc = $doc]
+			/// Overflow is not possible.
+			#[inline(always)]
+			pub fn full_mul(self, other: $name) -> $wide {
+				let product = uint_full_mul_reg!($name, $n_words, self, other);
+				let mut ret = [0; $wide_words];
+				ret[..$n_words * 2].copy_from_slice(&product);
+				$wide(ret)
+			}
 		}
-		let mut ret = [0; 4];
-		ret[0] = arr[0];
-		ret[1] = arr[1];
-		ret[2] = arr[2];
-		ret[3] = arr[3];
-		Ok(U256(ret))
-	}
+	};
 }
+
+impl_uint_full_mul!(U128, 2, U256, 4, "Multiplies two 128-bit integers to produce full 256-bit integer.");
+impl_uint_full_mul!(U256, 4, U512, 8, "Multiplies two 256-bit integers to produce full 512-bit integer.");
+impl_uint_full_mul!(U448, 7, U1024, 16, "Multiplies two 448-bit integers to produce full 896-bit integer.");
+impl_uint_full_mul!(U456, 8, U1024, 16, "Multiplies two `U456` integers, which hold up to 512 bits, to produce full 1024-bit integer.");
+impl_uint_full_mul!(U512, 8, U1024, 16, "Multiplies two 512-bit integers to produce full 1024-bit integer.");
+// `U1024` and `U1368` have no `full_mul`, there being no wider type for the product.
+
+impl_uint_widening_conversions!(U128, 2, U256, 4);
+impl_uint_widening_conversions!(U128, 2, U448, 7);
+impl_uint_widening_conversions!(U128, 2, U456, 8);
+impl_uint_widening_conversions!(U128, 2, U512, 8);
+impl_uint_widening_conversions!(U128, 2, U1024, 16);
+impl_uint_widening_conversions!(U128, 2, U1368, 22);
+impl_uint_widening_conversions!(U256, 4, U448, 7);
+impl_uint_widening_conversions!(U256, 4, U456, 8);
+impl_uint_widening_conversions!(U256, 4, U512, 8);
+impl_uint_widening_conversions!(U256, 4, U1024, 16);
+impl_uint_widening_conversions!(U256, 4, U1368, 22);
+impl_uint_widening_conversions!(U448, 7, U456, 8);
+impl_uint_widening_conversions!(U448, 7, U512, 8);
+impl_uint_widening_conversions!(U448, 7, U1024, 16);
+impl_uint_widening_conversions!(U448, 7, U1368, 22);
+// `U456` is stored in the same 8 words as `U512`, so this `TryFrom` never fails.
+impl_uint_widening_conversions!(U456, 8, U512, 8);
+impl_uint_widening_conversions!(U456, 8, U1024, 16);
+impl_uint_widening_conversions!(U456, 8, U1368, 22);
+impl_uint_widening_conversions!(U512, 8, U1024, 16);
+impl_uint_widening_conversions!(U512, 8, U1368, 22);
+impl_uint_widening_conversions!(U1024, 16, U1368, 22);
@@ -0,0 +1,131 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use primitive_types::{Decimal, FromDecimalStrErr, Rounding, U256};
+
+type Amount = Decimal<18>;
+
+fn amount(s: &str) -> Amount {
+	s.parse().unwrap()
+}
+
+#[test]
+fn parse() {
+	assert_eq!(amount("1.5").into_raw(), U256::exp10(17) * 15);
+	assert_eq!(amount("1").into_raw(), U256::exp10(18));
+	assert_eq!(amount("1."), amount("1"));
+	assert_eq!(amount(".5"), amount("0.5"));
+	assert_eq!(amount("0.000000000000000001").into_raw(), U256::one());
+	assert_eq!(amount("2.5000000000000000000000"), amount("2.5"));
+	assert_eq!(amount("0"), Amount::zero());
+
+	assert_eq!("".parse::<Amount>(), Err(FromDecimalStrErr::InvalidCharacter));
+	assert_eq!(".".parse::<Amount>(), Err(FromDecimalStrErr::InvalidCharacter));
+	assert_eq!("1.2.3".parse::<Amount>(), Err(FromDecimalStrErr::InvalidCharacter));
+	assert_eq!("-1".parse::<Amount>(), Err(FromDecimalStrErr::InvalidCharacter));
+	assert_eq!("1e18".parse::<Amount>(), Err(FromDecimalStrErr::InvalidCharacter));
+	assert_eq!("0.0000000000000000001".parse::<Amount>(), Err(FromDecimalStrErr::TooManyDecimals));
+	assert_eq!(format!("0.{}", "1".repeat(80)).parse::<Amount>(), Err(FromDecimalStrErr::TooManyDecimals));
+	assert_eq!(format!("{}", U256::MAX).parse::<Amount>(), Err(FromDecimalStrErr::Overflow));
+	assert_eq!("1".repeat(100).parse::<Amount>(), Err(FromDecimalStrErr::Overflow));
+}
+
+#[test]
+fn display() {
+	assert_eq!(amount("1.5").to_string(), "1.5");
+	assert_eq!(amount("1").to_string(), "1");
+	assert_eq!(amount("0.000021").to_string(), "0.000021");
+	assert_eq!(Amount::zero().to_string(), "0");
+	assert_eq!(format!("{:?}", amount("10.01")), "10.01");
+	assert_eq!(format!("{:>8}", amount("1.5")), "     1.5");
+	assert_eq!(format!("{:08}", amount("1.5")), "000001.5");
+
+	let max = Amount::max_value();
+	assert_eq!(max.to_string().parse::<Amount>(), Ok(max));
+	assert_eq!(format!("{:.0}", max), (max.trunc() + 1).to_string());
+	assert_eq!(Decimal::<0>::from_raw(U256::from(7)).to_string(), "7");
+	assert_eq!(format!("{:.2}", Decimal::<0>::from_raw(U256::from(7))), "7.00");
+}
+
+#[test]
+fn display_precision_and_rounding() {
+	assert_eq!(format!("{:.2}", amount("1.005")), "1.00");
+	assert_eq!(format!("{:.2}", amount("1.015")), "1.02");
+	assert_eq!(format!("{:.2}", amount("1.0151")), "1.02");
+	assert_eq!(format!("{:.0}", amount("0.5")), "0");
+	assert_eq!(format!("{:.0}", amount("9.5")), "10");
+	assert_eq!(format!("{:.20}", amount("1.5")), "1.50000000000000000000");
+	assert_eq!(format!("{:.18}", amount("1.5")), "1.500000000000000000");
+
+	let x = amount("2.345");
+	assert_eq!(x.display(2, Rounding::Down).to_string(), "2.34");
+	assert_eq!(x.display(2, Rounding::Up).to_string(), "2.35");
+	assert_eq!(x.display(2, Rounding::HalfUp).to_string(), "2.35");
+	assert_eq!(x.display(2, Rounding::HalfEven).to_string(), "2.34");
+	assert_eq!(x.display(1, Rounding::Up).to_string(), "2.4");
+	assert_eq!(x.display(0, Rounding::Down).to_string(), "2");
+	assert_eq!(amount("0.999").display(2, Rounding::Up).to_string(), "1.00");
+	assert_eq!(format!("{:>7}", x.display(2, Rounding::Down)), "   2.34");
+}
+
+#[test]
+fn round() {
+	assert_eq!(amount("2.345").round(2, Rounding::HalfUp), Some(amount("2.35")));
+	assert_eq!(amount("2.345").round(2, Rounding::Down), Some(amount("2.34")));
+	assert_eq!(amount("2.345").round(18, Rounding::Up), Some(amount("2.345")));
+	assert_eq!(Amount::max_value().round(0, Rounding::Up), None);
+	assert_eq!(Amount::max_value().round(0, Rounding::Down), Amount::from_integer(Amount::max_value().trunc()));
+}
+
+#[test]
+fn checked_arithmetic() {
+	assert_eq!(amount("1.5").checked_add(amount("2.25")), Some(amount("3.75")));
+	assert_eq!(amount("1.5").checked_sub(amount("0.25")), Some(amount("1.25")));
+	assert_eq!(amount("1.5").checked_sub(amount("2")), None);
+	assert_eq!(Amount::max_value().checked_add(Amount::from_raw(U256::one())), None);
+
+	assert_eq!(amount("1.5").checked_mul(amount("2.5")), Some(amount("3.75")));
+	assert_eq!(amount("0.000000001").checked_mul(amount("0.000000001")), Some(amount("0.000000000000000001")));
+	assert_eq!(amount("0.000000001").checked_mul(amount("0.0000000001")), Some(Amount::zero()));
+	assert_eq!(amount("1").checked_mul(Amount::max_value()), Some(Amount::max_value()));
+	assert_eq!(amount("2").checked_mul(Amount::max_value()), None);
+
+	assert_eq!(amount("3.75").checked_div(amount("1.5")), Some(amount("2.5")));
+	assert_eq!(amount("2").checked_div(amount("3")), Some(amount("0.666666666666666666")));
+	assert_eq!(amount("1").checked_div(Amount::zero()), None);
+	assert_eq!(Amount::max_value().checked_div(amount("0.5")), None);
+
+	assert_eq!(Amount::from_integer(U256::from(42)), Some(amount("42")));
+	assert_eq!(Amount::from_integer(U256::MAX), None);
+	assert_eq!(amount("42.125").trunc(), U256::from(42));
+	assert_eq!(amount("42.125").fract(), U256::exp10(15) * 125);
+}
+
+#[test]
+fn rescale() {
+	let x = amount("1.23456789");
+	assert_eq!(x.rescale::<6>(Rounding::Down), Some(Decimal::<6>::from_raw(U256::from(1_234_567))));
+	assert_eq!(x.rescale::<6>(Rounding::HalfEven), Some(Decimal::<6>::from_raw(U256::from(1_234_568))));
+	assert_eq!(x.rescale::<24>(Rounding::Down).map(|x| x.to_string()), Some("1.23456789".into()));
+	assert_eq!(x.rescale::<18>(Rounding::Up), Some(x));
+	assert_eq!(Amount::max_value().rescale::<19>(Rounding::Down), None);
+	assert_eq!(Decimal::<77>::UNIT, U256::exp10(77));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+	let x = amount("1234.5");
+	assert_eq!(serde_json::to_string(&x).unwrap(), r#""1234.5""#);
+	assert_eq!(serde_json::from_str::<Amount>(r#""1234.5""#).unwrap(), x);
+	assert_eq!(serde_json::from_str::<Amount>(r#""0.000000000000000001""#).unwrap(), Amount::from_raw(U256::one()));
+
+	let err = serde_json::from_str::<Amount>(r#""1.0000000000000000001""#).unwrap_err();
+	assert!(err.to_string().contains("too many fractional digits"), "{}", err);
+	assert!(serde_json::from_str::<Amount>("1").is_err());
+}
@@ -6,9 +6,10 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! Testing to and from f64 lossy for U256 primitive type.
+//! Testing conversions between uint types and floats.
 
-use primitive_types::{H160, H176, H256, U256};
+use core::convert::TryFrom;
+use primitive_types::{FromFloatError, H160, H176, H256, U1024, U128, U1368, U256, U448, U456, U512};
 
 #[test]
 #[allow(clippy::float_cmp)]
@@ -75,6 +76,98 @@ fn f64_to_u256_truncation() {
 	assert_eq!(U256::from_f64_lossy(10.5), 10.into());
 }
 
+#[test]
+#[allow(clippy::float_cmp)]
+fn to_float_rounds_like_primitives() {
+	let mut values = vec![0, 1, u64::MAX as u128, u128::MAX, 1 << 127, (1 << 127) - 1];
+	// halfway cases for both `f64` and `f32`, with and without bits below the halfway point
+	for &mantissa in &[1u128 << 52 | 1, 1 << 52, 1 << 23 | 1, 1 << 23] {
+		for &shift in &[11u32, 40, 70] {
+			let half = 1u128 << (shift - 1);
+			let base = mantissa << shift;
+			values.extend_from_slice(&[base + half, base + half + 1, base + half - 1]);
+		}
+	}
+	let mut x = 0x0123_4567_89ab_cdefu128;
+	for _ in 0..1000 {
+		x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+		values.push(x);
+		values.push(x >> (x % 128));
+	}
+
+	for &value in &values {
+		assert_eq!(U128::from(value).to_f64(), value as f64, "{}", value);
+		assert_eq!(U128::from(value).to_f32(), value as f32, "{}", value);
+		// same result with more words and the value shifted up
+		let wide = U256::from(value) << 100;
+		assert_eq!(wide.to_f64(), value as f64 * 2f64.powi(100), "{}", value);
+		assert_eq!(U1368::from(value).to_f64(), value as f64, "{}", value);
+	}
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn to_float_overflow() {
+	assert_eq!(U1024::MAX.to_f64(), f64::INFINITY);
+	assert_eq!(U1368::MAX.to_f64(), f64::INFINITY);
+	assert_eq!((U1024::one() << 1023).to_f64(), 2f64.powi(1023));
+	// halfway between `f64::MAX` and 2^1024, ties to even rounds up
+	let halfway = U1024::MAX - (U1024::one() << 970) + 1;
+	assert_eq!(halfway.to_f64(), f64::INFINITY);
+	assert_eq!((halfway - 1).to_f64(), f64::MAX);
+	assert_eq!(U256::MAX.to_f32(), f32::INFINITY);
+	assert_eq!(U128::MAX.to_f32(), u128::MAX as f32);
+	assert_eq!((U256::one() << 127).to_f32(), 2f32.powi(127));
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn to_f64_lossy_no_longer_truncates() {
+	// 2^64 + 2^12 + 1 is closer to 2^64 + 2^12 than to 2^64
+	let x = (U256::one() << 64) + (U256::one() << 12) + 1;
+	assert_eq!(x.to_f64_lossy(), 18446744073709555712.0);
+	assert_eq!(x.to_f64_lossy(), x.to_f64());
+}
+
+#[test]
+fn from_float_all_widths() {
+	assert_eq!(U128::from_f64_lossy(1e38), U128::from(1e38 as u128));
+	assert_eq!(U128::from_f64_lossy(1e39), U128::MAX);
+	assert_eq!(U512::from_f64_lossy(2f64.powi(500)), U512::one() << 500);
+	assert_eq!(U512::from_f64_lossy(2f64.powi(512)), U512::MAX);
+	assert_eq!(U1368::from_f64_lossy(f64::MAX), U1368::from(0x1f_ffff_ffff_ffffu64) << 971);
+	assert_eq!(U1368::from_f64_lossy(f64::INFINITY), U1368::MAX);
+	assert_eq!(U448::from_f64_lossy(-1.0), U448::zero());
+	assert_eq!(U456::from_f64_lossy(7.9), U456::from(7));
+	assert_eq!(U1024::from_f64_lossy(f64::NAN), U1024::zero());
+
+	assert_eq!(U256::from_f32_lossy(13.37), U256::from(13));
+	assert_eq!(U256::from_f32_lossy(f32::MAX), U256::from(u128::MAX) - (U256::one() << 104) + 1);
+	assert_eq!(U128::from_f32_lossy(f32::INFINITY), U128::MAX);
+	assert_eq!(U128::from_f32_lossy(-0.5), U128::zero());
+}
+
+#[test]
+fn try_from_float() {
+	assert_eq!(U256::try_from(42.0f64), Ok(U256::from(42)));
+	assert_eq!(U256::try_from(0.0f64), Ok(U256::zero()));
+	assert_eq!(U256::try_from(-0.0f64), Ok(U256::zero()));
+	assert_eq!(U256::try_from(2f64.powi(255)), Ok(U256::one() << 255));
+	assert_eq!(U256::try_from(2f64.powi(256)), Err(FromFloatError::Overflow));
+	assert_eq!(U256::try_from(f64::INFINITY), Err(FromFloatError::Overflow));
+	assert_eq!(U256::try_from(f64::NAN), Err(FromFloatError::NotANumber));
+	assert_eq!(U256::try_from(-1.0f64), Err(FromFloatError::Negative));
+	assert_eq!(U256::try_from(f64::NEG_INFINITY), Err(FromFloatError::Negative));
+	assert_eq!(U256::try_from(10.5f64), Err(FromFloatError::Fractional));
+	assert_eq!(U256::try_from(f64::EPSILON), Err(FromFloatError::Fractional));
+	assert_eq!(U1368::try_from(f64::MAX), Ok(U1368::from_f64_lossy(f64::MAX)));
+
+	assert_eq!(U128::try_from(3.0f32), Ok(U128::from(3)));
+	assert_eq!(U128::try_from(0.25f32), Err(FromFloatError::Fractional));
+	assert_eq!(U128::try_from(f32::MAX), Ok(U128::from(f32::MAX as u128)));
+	assert_eq!(U128::try_from(f32::NAN), Err(FromFloatError::NotANumber));
+}
+
 #[test]
 fn hash_prefix() {
 	assert_eq!(H176::default().to_string(), "00000000000000000000000000000000000000000000");
@@ -0,0 +1,81 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use primitive_types::{IcanError, IcanNetwork, H160, H176};
+
+const MAINNET: &str = "cb57bbbb54cdf60fa666fd741be78f794d4608d67109";
+const MAINNET_2: &str = "cb82a5fd22b9bee8b8ab877c86e0a2c21765e1d5bfc5";
+const TESTNET: &str = "ab792215c43fc213c02182c8389f2bc32408e2c50922";
+
+#[test]
+fn parse_and_format() {
+	for &s in &[MAINNET, MAINNET_2, TESTNET] {
+		let address = H176::from_ican_str(s).unwrap();
+		assert_eq!(address.to_string(), s);
+		assert_eq!(format!("{:x}", address), s);
+		assert_eq!(H176::from_ican_str(&format!("0x{}", s)), Ok(address));
+		assert_eq!(H176::from_ican_str(&s.to_uppercase()), Ok(address));
+	}
+
+	let address = H176::from_ican_str(MAINNET).unwrap();
+	assert_eq!(address.ican_network(), Some(IcanNetwork::Mainnet));
+	assert_eq!(address.ican_body(), H160::from_slice(&address[2..]));
+	assert_eq!(H176::from_ican_str(TESTNET).unwrap().ican_network(), Some(IcanNetwork::Testnet));
+}
+
+#[test]
+fn checksum() {
+	let body = H176::from_ican_str(MAINNET).unwrap().ican_body();
+	assert_eq!(H176::ican_checksum(IcanNetwork::Mainnet, &body), 57);
+	assert_eq!(H176::from_ican_parts(IcanNetwork::Mainnet, &body), H176::from_ican_str(MAINNET).unwrap());
+
+	// same body on a different network gets different check digits
+	let private = H176::from_ican_parts(IcanNetwork::Private, &body);
+	assert_eq!(private[0], 0xce);
+	assert!(private.validate_ican().is_ok());
+	assert_ne!(private[1], 0x57);
+
+	let zero = H176::from_ican_parts(IcanNetwork::Private, &H160::zero());
+	assert_eq!(zero.to_string(), "ce450000000000000000000000000000000000000000");
+
+	// check digits are always two decimal digits
+	for i in 0..=255u8 {
+		let checksum = H176::ican_checksum(IcanNetwork::Testnet, &H160::repeat_byte(i));
+		assert!((2..=98).contains(&checksum));
+	}
+}
+
+#[test]
+fn errors() {
+	assert_eq!(H176::from_ican_str(""), Err(IcanError::InvalidLength));
+	assert_eq!(H176::from_ican_str(&MAINNET[..42]), Err(IcanError::InvalidLength));
+	assert_eq!(H176::from_ican_str(&format!("{}00", MAINNET)), Err(IcanError::InvalidLength));
+	assert_eq!(H176::from_ican_str(&MAINNET.replace('b', "g")), Err(IcanError::InvalidHex));
+	assert_eq!(H176::from_ican_str(&format!("aa{}", &MAINNET[2..])), Err(IcanError::UnknownNetwork));
+	assert_eq!(H176::from_ican_str(&format!("cb58{}", &MAINNET[4..])), Err(IcanError::InvalidChecksum));
+	// a mainnet address moved to testnet keeps its check digits and fails
+	assert_eq!(H176::from_ican_str(&format!("ab{}", &MAINNET[2..])), Err(IcanError::InvalidChecksum));
+	// a single changed body digit is caught
+	assert_eq!(H176::from_ican_str(&MAINNET.replacen("bbbb", "bbbc", 1)), Err(IcanError::InvalidChecksum));
+	assert_eq!(H176::zero().validate_ican(), Err(IcanError::UnknownNetwork));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_validates() {
+	let address = H176::from_ican_str(MAINNET).unwrap();
+	let json = serde_json::to_string(&address).unwrap();
+	assert_eq!(json, format!("\"{}\"", MAINNET));
+	assert_eq!(serde_json::from_str::<H176>(&json).unwrap(), address);
+
+	let err = serde_json::from_str::<H176>(&format!("\"cb58{}\"", &MAINNET[4..])).unwrap_err();
+	assert!(err.to_string().contains("checksum mismatch"), "{}", err);
+	let err = serde_json::from_str::<H176>(&format!("\"{}\"", "0".repeat(44))).unwrap_err();
+	assert!(err.to_string().contains("unknown network"), "{}", err);
+	assert!(serde_json::from_str::<H176>(&format!("\"{}\"", &MAINNET[2..])).is_err());
+}
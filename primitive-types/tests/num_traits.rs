@@ -8,7 +8,7 @@
 
 use impl_num_traits::integer_sqrt::IntegerSquareRoot;
 use num_traits::ops::checked::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
-use primitive_types::U256;
+use primitive_types::{U1368, U256, U448};
 
 #[test]
 fn u256_isqrt() {
@@ -35,3 +35,11 @@ fn u256_checked_traits_supported() {
 	assert_eq!(<U256 as CheckedMul>::checked_mul(MAX, MAX), None);
 	assert_eq!(<U256 as CheckedMul>::checked_mul(MAX, ZERO), Some(*ZERO));
 }
+
+#[test]
+fn every_width_supported() {
+	assert_eq!(<U448 as CheckedAdd>::checked_add(&U448::MAX, &U448::one()), None);
+	assert_eq!(U448::from(1u64 << 40).integer_sqrt(), U448::from(1u64 << 20));
+	assert_eq!(<U1368 as CheckedMul>::checked_mul(&U1368::MAX, &U1368::from(2)), None);
+	assert_eq!(<U1368 as num_traits::Num>::from_str_radix("ff", 16).unwrap(), U1368::from(255));
+}
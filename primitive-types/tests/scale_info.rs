@@ -8,7 +8,7 @@
 
 //! Tests for scale-info feature of primitive-types.
 
-use primitive_types::{H256, U256};
+use primitive_types::{H256, H448, U256, U448};
 use scale_info_crate::{build::Fields, Path, Type, TypeInfo};
 
 #[test]
@@ -28,3 +28,16 @@ fn h256_scale_info() {
 
 	assert_eq!(H256::type_info(), r#type.into());
 }
+
+#[test]
+fn u448_h448_scale_info() {
+	let r#type = Type::builder()
+		.path(Path::new("U448", "primitive_types"))
+		.composite(Fields::unnamed().field(|f| f.ty::<[u64; 7]>().type_name("[u64; 7]")));
+	assert_eq!(U448::type_info(), r#type);
+
+	let r#type = Type::builder()
+		.path(Path::new("H448", "primitive_types"))
+		.composite(Fields::unnamed().field(|f| f.ty::<[u8; 56]>().type_name("[u8; 56]")));
+	assert_eq!(H448::type_info(), r#type);
+}
@@ -0,0 +1,294 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Generates Merkle-Patricia trie roots from key-value pairs without building
+//! the trie.
+//!
+//! Every function is generic over the [`Hasher`] that hashes the nodes, so
+//! `keccak_hash::KeccakHasher` gives Ethereum roots and
+//! `keccak_hash::Sha3Hasher` gives Core roots.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+use core::{cmp, iter::once};
+use hash_db::Hasher;
+use rlp::RlpStream;
+
+/// Generates a trie root hash for a vector of values, keyed by the RLP
+/// encoding of their index, as for transactions and receipts.
+///
+/// ```
+/// use keccak_hash::KeccakHasher;
+/// use triehash::{ordered_trie_root, trie_root};
+///
+/// let v = &["doe", "reindeer"];
+/// let keyed = vec![(rlp::encode(&0usize), "doe"), (rlp::encode(&1usize), "reindeer")];
+/// assert_eq!(ordered_trie_root::<KeccakHasher, _>(v), trie_root::<KeccakHasher, _, _, _>(keyed));
+/// ```
+pub fn ordered_trie_root<H, I>(input: I) -> H::Out
+where
+	I: IntoIterator,
+	I::Item: AsRef<[u8]>,
+	H: Hasher,
+{
+	trie_root::<H, _, _, _>(input.into_iter().enumerate().map(|(i, v)| (rlp::encode(&i), v)))
+}
+
+/// Generates a trie root hash for a vector of key-value tuples.
+///
+/// The order of the pairs does not matter. Of pairs with the same key, the
+/// last one wins.
+///
+/// ```
+/// use hex_literal::hex;
+/// use keccak_hash::KeccakHasher;
+/// use triehash::trie_root;
+///
+/// let v = vec![("doe", "reindeer"), ("dog", "puppy"), ("dogglesworth", "cat")];
+/// let root = hex!("8aad789dff2f538bca5d8ea56e8abe10f4c7ba3a5dea95fea4cd6e7c3a1168d3");
+/// assert_eq!(trie_root::<KeccakHasher, _, _, _>(v).as_bytes(), &root);
+/// ```
+pub fn trie_root<H, I, A, B>(input: I) -> H::Out
+where
+	I: IntoIterator<Item = (A, B)>,
+	A: AsRef<[u8]> + Ord,
+	B: AsRef<[u8]>,
+	H: Hasher,
+{
+	// sort by key and drop duplicates
+	let input = input.into_iter().collect::<BTreeMap<_, _>>();
+
+	// split the keys into nibbles, all in one buffer
+	let mut nibbles = Vec::with_capacity(input.keys().map(|k| k.as_ref().len()).sum::<usize>() * 2);
+	let mut lens = Vec::with_capacity(input.len() + 1);
+	lens.push(0);
+	for k in input.keys() {
+		for &b in k.as_ref() {
+			nibbles.push(b >> 4);
+			nibbles.push(b & 0x0f);
+		}
+		lens.push(nibbles.len());
+	}
+
+	let input = input
+		.into_iter()
+		.zip(lens.windows(2))
+		.map(|((_, v), w)| (&nibbles[w[0]..w[1]], v))
+		.collect::<Vec<_>>();
+
+	let mut stream = RlpStream::new();
+	hash256rlp::<H, _, _>(&input, 0, &mut stream);
+	H::hash(&stream.out())
+}
+
+/// Generates a secure trie root hash for a vector of key-value tuples, where
+/// each key is hashed with `H` first, as for account state.
+///
+/// ```
+/// use keccak_hash::{keccak, KeccakHasher};
+/// use triehash::{sec_trie_root, trie_root};
+///
+/// let v = vec![("doe", "reindeer"), ("dog", "puppy")];
+/// let hashed = vec![(keccak("doe"), "reindeer"), (keccak("dog"), "puppy")];
+/// assert_eq!(sec_trie_root::<KeccakHasher, _, _, _>(v), trie_root::<KeccakHasher, _, _, _>(hashed));
+/// ```
+pub fn sec_trie_root<H, I, A, B>(input: I) -> H::Out
+where
+	I: IntoIterator<Item = (A, B)>,
+	A: AsRef<[u8]>,
+	B: AsRef<[u8]>,
+	H: Hasher,
+{
+	trie_root::<H, _, _, _>(input.into_iter().map(|(k, v)| (H::hash(k.as_ref()), v)))
+}
+
+fn shared_prefix_len<T: Eq>(first: &[T], second: &[T]) -> usize {
+	first
+		.iter()
+		.zip(second.iter())
+		.position(|(f, s)| f != s)
+		.unwrap_or_else(|| cmp::min(first.len(), second.len()))
+}
+
+/// Hex-prefix encoding of a nibble path, with the node type and the oddness
+/// of the path in the first nibble.
+fn hex_prefix_encode(nibbles: &[u8], leaf: bool) -> impl Iterator<Item = u8> + '_ {
+	let odd = nibbles.len() % 2;
+	let mut first_byte = ((odd as u8) + 2 * (leaf as u8)) << 4;
+	if odd == 1 {
+		first_byte += nibbles[0];
+	}
+	once(first_byte).chain(nibbles[odd..].chunks(2).map(|ch| (ch[0] << 4) | ch[1]))
+}
+
+/// Appends the node for `input`, whose keys all share the first `pre_len`
+/// nibbles, to `stream`.
+fn hash256rlp<H, A, B>(input: &[(A, B)], pre_len: usize, stream: &mut RlpStream)
+where
+	A: AsRef<[u8]>,
+	B: AsRef<[u8]>,
+	H: Hasher,
+{
+	if input.is_empty() {
+		stream.append_empty_data();
+		return
+	}
+
+	let key: &[u8] = input[0].0.as_ref();
+	let value: &[u8] = input[0].1.as_ref();
+
+	// a single pair is a leaf with the rest of its key
+	if input.len() == 1 {
+		stream.begin_list(2);
+		stream.append_iter(hex_prefix_encode(&key[pre_len..], true));
+		stream.append(&value);
+		return
+	}
+
+	// the longest prefix shared by all keys
+	let shared_prefix = input
+		.iter()
+		.skip(1)
+		.fold(key.len(), |acc, (k, _)| cmp::min(shared_prefix_len(key, k.as_ref()), acc));
+
+	// an extension for the part of the key all pairs share
+	if shared_prefix > pre_len {
+		stream.begin_list(2);
+		stream.append_iter(hex_prefix_encode(&key[pre_len..shared_prefix], false));
+		hash256aux::<H, _, _>(input, shared_prefix, stream);
+		return
+	}
+
+	// a branch with a child for every next nibble, and the value of the key
+	// that ends here if there is one
+	stream.begin_list(17);
+	let ends_here = pre_len == key.len();
+	let mut begin = if ends_here { 1 } else { 0 };
+	for i in 0..16 {
+		let len = input[begin..].iter().take_while(|pair| pair.0.as_ref()[pre_len] == i).count();
+		if len == 0 {
+			stream.append_empty_data();
+		} else {
+			hash256aux::<H, _, _>(&input[begin..begin + len], pre_len + 1, stream);
+		}
+		begin += len;
+	}
+	if ends_here {
+		stream.append(&value);
+	} else {
+		stream.append_empty_data();
+	}
+}
+
+/// Appends a child node, inline if its encoding is shorter than a hash and by
+/// hash otherwise.
+fn hash256aux<H, A, B>(input: &[(A, B)], pre_len: usize, stream: &mut RlpStream)
+where
+	A: AsRef<[u8]>,
+	B: AsRef<[u8]>,
+	H: Hasher,
+{
+	let mut s = RlpStream::new();
+	hash256rlp::<H, _, _>(input, pre_len, &mut s);
+	let out = s.out();
+	if out.len() < 32 {
+		stream.append_raw(&out, 1);
+	} else {
+		stream.append(&H::hash(&out).as_ref());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[cfg(not(feature = "std"))]
+	use alloc::{vec, vec::Vec};
+
+	use super::*;
+	use hex_literal::hex;
+	use keccak_hash::{keccak, sha3, KeccakHasher, Sha3Hasher, H256, KECCAK_NULL_RLP, SHA3_NULL_RLP};
+
+	#[test]
+	fn empty_trie() {
+		assert_eq!(ordered_trie_root::<KeccakHasher, Vec<Vec<u8>>>(vec![]), KECCAK_NULL_RLP);
+		assert_eq!(trie_root::<KeccakHasher, Vec<(Vec<u8>, Vec<u8>)>, _, _>(vec![]), KECCAK_NULL_RLP);
+		assert_eq!(sec_trie_root::<KeccakHasher, Vec<(Vec<u8>, Vec<u8>)>, _, _>(vec![]), KECCAK_NULL_RLP);
+		assert_eq!(ordered_trie_root::<Sha3Hasher, Vec<Vec<u8>>>(vec![]), SHA3_NULL_RLP);
+	}
+
+	#[test]
+	fn single_leaf() {
+		// [0x20 ++ "A", "aaa…a"] is longer than 32 bytes, so the root is its hash
+		let value = [b'a'; 40];
+		let mut leaf = vec![0xc0 + 44, 0x82, 0x20, b'A', 0xa8];
+		leaf.extend_from_slice(&value);
+		assert_eq!(trie_root::<KeccakHasher, _, _, _>(vec![(b"A", &value)]), keccak(&leaf));
+		assert_eq!(trie_root::<Sha3Hasher, _, _, _>(vec![(b"A", &value)]), sha3(&leaf));
+	}
+
+	#[test]
+	fn known_roots() {
+		let v = vec![("doe", "reindeer"), ("dog", "puppy"), ("dogglesworth", "cat")];
+		assert_eq!(
+			trie_root::<KeccakHasher, _, _, _>(v),
+			H256(hex!("8aad789dff2f538bca5d8ea56e8abe10f4c7ba3a5dea95fea4cd6e7c3a1168d3"))
+		);
+
+		let v = vec![("do", "verb"), ("dog", "puppy"), ("doge", "coin"), ("horse", "stallion")];
+		assert_eq!(
+			trie_root::<KeccakHasher, _, _, _>(v),
+			H256(hex!("5991bb8c6514148a29db676a14ac506cd2cd5775ace63c30a4fe457715e9ac84"))
+		);
+	}
+
+	#[test]
+	fn out_of_order_and_duplicates() {
+		let ordered = vec![
+			(vec![0x01u8, 0x23], vec![0x01u8, 0x23]),
+			(vec![0x81, 0x23], vec![0x81, 0x23]),
+			(vec![0xf1, 0x23], vec![0xf1, 0x23]),
+		];
+		let shuffled = vec![
+			(vec![0xf1u8, 0x23], vec![0x00u8]),
+			(vec![0x01, 0x23], vec![0x01, 0x23]),
+			(vec![0x81, 0x23], vec![0x81, 0x23]),
+			(vec![0xf1, 0x23], vec![0xf1, 0x23]),
+		];
+		assert_eq!(trie_root::<KeccakHasher, _, _, _>(ordered.clone()), trie_root::<KeccakHasher, _, _, _>(shuffled));
+		assert_ne!(trie_root::<KeccakHasher, _, _, _>(ordered.clone()), trie_root::<Sha3Hasher, _, _, _>(ordered));
+	}
+
+	#[test]
+	fn ordered_is_keyed_by_rlp_index() {
+		let values: Vec<Vec<u8>> = (0..200u32).map(|i| i.to_be_bytes().repeat(i as usize % 10)).collect();
+		let keyed = values.iter().enumerate().map(|(i, v)| (rlp::encode(&i), v));
+		assert_eq!(ordered_trie_root::<Sha3Hasher, _>(&values), trie_root::<Sha3Hasher, _, _, _>(keyed));
+	}
+
+	#[test]
+	fn secure_trie_hashes_keys() {
+		let v = vec![("doe", "reindeer"), ("dog", "puppy"), ("dogglesworth", "cat")];
+		let hashed = v.iter().map(|&(k, v)| (sha3(k), v)).collect::<Vec<_>>();
+		assert_eq!(sec_trie_root::<Sha3Hasher, _, _, _>(v), trie_root::<Sha3Hasher, _, _, _>(hashed));
+	}
+
+	#[test]
+	fn hex_prefix() {
+		assert_eq!(hex_prefix_encode(&[1, 2, 3, 4, 5], false).collect::<Vec<_>>(), vec![0x11, 0x23, 0x45]);
+		assert_eq!(hex_prefix_encode(&[0, 1, 2, 3, 4, 5], false).collect::<Vec<_>>(), vec![0x00, 0x01, 0x23, 0x45]);
+		assert_eq!(hex_prefix_encode(&[0, 15, 1, 12, 11, 8], true).collect::<Vec<_>>(), vec![0x20, 0x0f, 0x1c, 0xb8]);
+		assert_eq!(hex_prefix_encode(&[15, 1, 12, 11, 8], true).collect::<Vec<_>>(), vec![0x3f, 0x1c, 0xb8]);
+	}
+}
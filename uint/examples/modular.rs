@@ -13,45 +13,69 @@ construct_uint! {
 	pub struct U256(4);
 }
 
+construct_uint! {
+	pub struct U448(7);
+}
+
+// imagine the field 0..p
+// where the p is defined below
+// (it's a prime!)
+// 38873241744847760218045702002058062581688990428170398542849190507947196700873
+const P: U256 = U256([0xe7dc7e64cef058c9, 0x3808050bf5930972, 0x718bf3f6fbc7edde, 0x55f179a1c57fefc3]);
+
+// the Ed448 "Goldilocks" prime 2^448 - 2^224 - 1
+const P448: U448 = U448([u64::MAX, u64::MAX, u64::MAX, 0xfffffffeffffffff, u64::MAX, u64::MAX, u64::MAX]);
+
+// elements of the fields are kept in Montgomery form,
+// so multiplication never needs a division
+construct_montgomery! {
+	pub struct Fp(U256, P);
+}
+
+construct_montgomery! {
+	pub struct Fp448(U448, P448);
+}
+
 fn main() {
 	// Example modular arithmetic using bigint U256 primitives
-
-	// imagine the field 0..p
-	// where the p is defined below
-	// (it's a prime!)
 	let p = U256::from_dec_str("38873241744847760218045702002058062581688990428170398542849190507947196700873")
 		.expect("p to be a good number in the example");
+	assert_eq!(p, Fp::MODULUS);
 
-	// then, on this field,
-	// (p-1) + (p+1) = 0
+	let p_minus_1 = Fp::from(p - 1);
+	let p_plus_1 = Fp::from(p + 1);
 
-	// (p - 1) mod p
-	let p_minus_1 = (p - 1) % p;
-	// (p + 1) mod p
-	let p_plus_1 = (p + 1) % p;
-	// ((p - 1) mod p + (p + 1) mod p) mod p
-	let sum = (p_minus_1 + p_plus_1) % p;
-	assert_eq!(sum, 0.into());
+	// on this field,
+	// (p-1) + (p+1) = 0
+	assert_eq!(p_minus_1 + p_plus_1, Fp::zero());
 
 	// on this field,
 	// (p-1) + (p-1) = p-2
-	let p_minus_1 = (p - 1) % p;
-	let sum = (p_minus_1 + p_minus_1) % p;
-	assert_eq!(sum, p - 2);
+	assert_eq!((p_minus_1 + p_minus_1).into_uint(), p - 2);
+
+	// on this field,
+	// 1 - 2 = p-1
+	assert_eq!(Fp::one() - Fp::from(2), p_minus_1);
 
 	// on this field,
 	// (p-1) * 3 = p-3
-	let p_minus_1 = (p - 1) % p;
-
-	// multiplication is a series of additions
-	let multiplicator = 3;
-	let mul = {
-		let mut result = p_minus_1;
-		for _ in 0..multiplicator - 1 {
-			result = (p_minus_1 + result) % p;
-		}
-		result
-	};
-
-	assert_eq!(mul, p - 3);
+	assert_eq!((p_minus_1 * Fp::from(3)).into_uint(), p - 3);
+
+	// (p-1) * (p-1) = 1
+	assert_eq!(p_minus_1.square(), Fp::one());
+
+	// every nonzero element has an inverse,
+	// which by Fermat's little theorem is x^(p-2)
+	let x = Fp::from(1234567);
+	let inv = x.inverse().expect("p is prime");
+	assert_eq!(x * inv, Fp::one());
+	assert_eq!(x.pow(p - 2), inv);
+
+	// one-off operations don't need a field type
+	assert_eq!((p - 1).mul_mod(U256::from(3), p), p - 3);
+
+	// the same works for any width
+	let x = Fp448::from(2);
+	assert_eq!(x.pow(P448 - 1), Fp448::one());
+	assert_eq!(x * x.inverse().expect("p is prime"), Fp448::one());
 }
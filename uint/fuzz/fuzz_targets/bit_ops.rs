@@ -0,0 +1,55 @@
+// Copyright 2021 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use uint::*;
+use rug::{Integer, integer::Order};
+
+
+construct_uint! {
+	pub struct U256(4);
+}
+
+fn from_gmp(x: Integer) -> Option<U256> {
+	let digits = x.to_digits(Order::LsfLe);
+	if digits.len() > 32 {
+		return None;
+	}
+	Some(U256::from_little_endian(&digits))
+}
+
+// bit i of the result is bit f(i) of x
+fn permute_bits(x: U256, f: impl Fn(usize) -> usize) -> U256 {
+	let mut ret = U256::zero();
+	for i in 0..256 {
+		if x.bit(f(i)) {
+			ret = ret | (U256::one() << i);
+		}
+	}
+	ret
+}
+
+fuzz_target!(|data: &[u8]| {
+	if data.len() == 34 {
+		let n = u32::from(u16::from_le_bytes([data[0], data[1]]));
+		let x = U256::from_little_endian(&data[2..]);
+		let x_gmp = Integer::from_digits(&data[2..], Order::LsfLe);
+
+		assert_eq!(x.count_ones(), x_gmp.count_ones().unwrap());
+		assert_eq!(x.count_zeros(), 256 - x_gmp.count_ones().unwrap());
+		assert_eq!(x.is_power_of_two(), x_gmp.is_power_of_two());
+		assert_eq!(x.checked_next_power_of_two(), from_gmp(x_gmp.next_power_of_two()));
+
+		let shift = n as usize % 256;
+		assert_eq!(x.rotate_left(n), permute_bits(x, |i| (i + 256 - shift) % 256));
+		assert_eq!(x.rotate_right(n), permute_bits(x, |i| (i + shift) % 256));
+		assert_eq!(x.reverse_bits(), permute_bits(x, |i| 255 - i));
+	}
+});
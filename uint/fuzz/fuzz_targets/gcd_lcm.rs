@@ -0,0 +1,37 @@
+// Copyright 2021 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use uint::*;
+use rug::{Integer, integer::Order};
+
+
+construct_uint! {
+	pub struct U512(8);
+}
+
+fn from_gmp(x: Integer) -> Option<U512> {
+	let digits = x.to_digits(Order::LsfLe);
+	if digits.len() > 64 {
+		return None;
+	}
+	Some(U512::from_little_endian(&digits))
+}
+
+fuzz_target!(|data: &[u8]| {
+	if data.len() == 128 {
+		let x = U512::from_little_endian(&data[..64]);
+		let y = U512::from_little_endian(&data[64..]);
+		let x_gmp = Integer::from_digits(&data[..64], Order::LsfLe);
+		let y_gmp = Integer::from_digits(&data[64..], Order::LsfLe);
+		assert_eq!(from_gmp(x_gmp.clone().gcd(&y_gmp)), Some(x.gcd(y)));
+		assert_eq!(from_gmp(x_gmp.lcm(&y_gmp)), x.checked_lcm(y));
+	}
+});
@@ -0,0 +1,48 @@
+// Copyright 2021 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use uint::*;
+use rug::{Integer, integer::Order};
+
+
+construct_uint! {
+	pub struct U256(4);
+}
+
+fn ilog(mut x: Integer, base: &Integer) -> Option<u32> {
+	if x == 0 || *base < 2 {
+		return None;
+	}
+	let mut n = 0;
+	while x >= *base {
+		x /= base;
+		n += 1;
+	}
+	Some(n)
+}
+
+fuzz_target!(|data: &[u8]| {
+	if data.len() == 64 {
+		let x = U256::from_little_endian(&data[..32]);
+		let base = U256::from_little_endian(&data[32..]);
+		let x_gmp = Integer::from_digits(&data[..32], Order::LsfLe);
+		let base_gmp = Integer::from_digits(&data[32..], Order::LsfLe);
+
+		let log2 = if x_gmp == 0 { None } else { Some(x_gmp.significant_bits() - 1) };
+		let log10 = if x_gmp == 0 { None } else { Some(x_gmp.to_string().len() as u32 - 1) };
+		assert_eq!(x.checked_ilog2(), log2);
+		assert_eq!(x.checked_ilog10(), log10);
+		assert_eq!(x.checked_ilog(base), ilog(x_gmp.clone(), &base_gmp));
+		// small bases take a different path
+		let small = U256::from(data[32]);
+		assert_eq!(x.checked_ilog(small), ilog(x_gmp, &Integer::from(data[32])));
+	}
+});
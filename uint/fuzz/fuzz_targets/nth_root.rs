@@ -0,0 +1,32 @@
+// Copyright 2021 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use uint::*;
+use rug::{Integer, integer::Order};
+
+
+construct_uint! {
+	pub struct U256(4);
+}
+
+fn from_gmp(x: Integer) -> U256 {
+	let digits = x.to_digits(Order::LsfLe);
+	U256::from_little_endian(&digits)
+}
+
+fuzz_target!(|data: &[u8]| {
+	if data.len() == 33 {
+		let n = u32::from(data[0]) + 1;
+		let x = U256::from_little_endian(&data[1..]);
+		let x_gmp = Integer::from_digits(&data[1..], Order::LsfLe);
+		assert_eq!(x.integer_nth_root(n), from_gmp(x_gmp.root(n)));
+	}
+});
@@ -0,0 +1,818 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Big signed integer types.
+//!
+//! Two's-complement signed integers built on top of the unsigned types
+//! generated by `construct_uint!`. A signed type has exactly the same limb
+//! layout as its unsigned counterpart, so converting between the two bit
+//! patterns is free.
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_int_binop {
+	($name:ident, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $op:ident) => {
+		impl<T> $crate::core_::ops::$trait<T> for $name where T: Into<$name> {
+			type Output = $name;
+
+			fn $fn(self, other: T) -> $name {
+				let (result, overflow) = self.$op(other.into());
+				$crate::panic_on_overflow!(overflow);
+				result
+			}
+		}
+
+		impl<'a, T> $crate::core_::ops::$trait<T> for &'a $name where T: Into<$name> {
+			type Output = $name;
+
+			fn $fn(self, other: T) -> $name {
+				$crate::core_::ops::$trait::$fn(*self, other)
+			}
+		}
+
+		impl<T> $crate::core_::ops::$assign_trait<T> for $name where T: Into<$name> {
+			fn $assign_fn(&mut self, other: T) {
+				*self = $crate::core_::ops::$trait::$fn(*self, other);
+			}
+		}
+	};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_int_bitop {
+	($name:ident, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+		impl $crate::core_::ops::$trait<$name> for $name {
+			type Output = $name;
+
+			#[inline]
+			fn $fn(self, other: $name) -> $name {
+				$name($crate::core_::ops::$trait::$fn(self.0, other.0))
+			}
+		}
+
+		impl $crate::core_::ops::$assign_trait<$name> for $name {
+			fn $assign_fn(&mut self, rhs: $name) {
+				*self = $crate::core_::ops::$trait::$fn(*self, rhs);
+			}
+		}
+	};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_int_from_signed {
+	($name:ident, $uint:ident, $from:ty, $via:ty, $via_unsigned:ty) => {
+		impl $crate::core_::convert::From<$from> for $name {
+			fn from(value: $from) -> $name {
+				let value = value as $via;
+				if value < 0 {
+					// `!value` is non-negative, so flipping the bits back sign-extends it.
+					$name(!$uint::from(!value as $via_unsigned))
+				} else {
+					$name($uint::from(value as $via_unsigned))
+				}
+			}
+		}
+	};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_int_from_unsigned {
+	($name:ident, $uint:ident, $from:ty) => {
+		impl $crate::core_::convert::From<$from> for $name {
+			fn from(value: $from) -> $name {
+				$name($uint::from(value as u64))
+			}
+		}
+	};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_try_from_int_for_primitive {
+	($name:ident, $to:ty) => {
+		impl $crate::core_::convert::TryFrom<$name> for $to {
+			type Error = &'static str;
+
+			#[inline]
+			fn try_from(i: $name) -> $crate::core_::result::Result<$to, &'static str> {
+				let err_str = concat!("integer overflow when casting to ", stringify!($to));
+				let i: i128 = $crate::core_::convert::TryFrom::try_from(i).map_err(|_| err_str)?;
+				$crate::core_::convert::TryFrom::try_from(i).map_err(|_| err_str)
+			}
+		}
+	};
+}
+
+/// Construct a signed two's-complement integer type on top of an unsigned
+/// type created by `construct_uint!`.
+///
+/// The unsigned type must be at least 128 bits wide.
+///
+/// # Example
+///
+/// ```
+/// use uint::{construct_int, construct_uint};
+///
+/// construct_uint! {
+///     pub struct U256(4);
+/// }
+///
+/// construct_int! {
+///     pub struct I256(U256);
+/// }
+///
+/// let a = I256::from(-7);
+/// let b = I256::from(2);
+/// assert_eq!(a / b, I256::from(-3));
+/// assert_eq!(a % b, I256::from(-1));
+/// assert_eq!(a >> 1, I256::from(-4));
+/// assert_eq!(a.to_string(), "-7");
+/// ```
+#[macro_export]
+macro_rules! construct_int {
+	( $(#[$attr:meta])* $visibility:vis struct $name:ident ( $uint:ident ); ) => {
+		/// Little-endian two's-complement large integer type
+		#[repr(transparent)]
+		$(#[$attr])*
+		#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+		$visibility struct $name ($uint);
+
+		$crate::static_assertions::const_assert!($crate::core_::mem::size_of::<$uint>() >= 16);
+
+		impl $name {
+			const BITS: usize = $crate::core_::mem::size_of::<$uint>() * 8;
+
+			/// Smallest value, `-2^(BITS - 1)`.
+			pub const MIN: $name = {
+				let mut words = $uint::zero().0;
+				let top = words.len() - 1;
+				words[top] = 1 << 63;
+				$name($uint(words))
+			};
+
+			/// Largest value, `2^(BITS - 1) - 1`.
+			pub const MAX: $name = {
+				let mut words = $uint::MAX.0;
+				let top = words.len() - 1;
+				words[top] = u64::max_value() >> 1;
+				$name($uint(words))
+			};
+
+			/// Reinterprets the two's-complement bit pattern of an unsigned value.
+			#[inline]
+			pub const fn from_raw(value: $uint) -> Self {
+				$name(value)
+			}
+
+			/// Returns the two's-complement bit pattern as an unsigned value.
+			#[inline]
+			pub const fn into_raw(self) -> $uint {
+				self.0
+			}
+
+			/// Zero (additive identity) of this type.
+			#[inline]
+			pub const fn zero() -> Self {
+				$name($uint::zero())
+			}
+
+			/// One (multiplicative identity) of this type.
+			#[inline]
+			pub const fn one() -> Self {
+				$name($uint::one())
+			}
+
+			/// Minus one of this type.
+			#[inline]
+			pub const fn minus_one() -> Self {
+				$name($uint::MAX)
+			}
+
+			/// Whether this is zero.
+			#[inline]
+			pub const fn is_zero(&self) -> bool {
+				self.0.is_zero()
+			}
+
+			/// Whether this is strictly less than zero.
+			#[inline]
+			pub const fn is_negative(&self) -> bool {
+				self.0.bit(Self::BITS - 1)
+			}
+
+			/// Whether this is strictly greater than zero.
+			#[inline]
+			pub const fn is_positive(&self) -> bool {
+				!self.is_negative() && !self.is_zero()
+			}
+
+			/// Returns `-1`, `0` or `1` depending on the sign of `self`.
+			pub fn signum(self) -> Self {
+				if self.is_negative() {
+					Self::minus_one()
+				} else if self.is_zero() {
+					Self::zero()
+				} else {
+					Self::one()
+				}
+			}
+
+			/// Returns the absolute value as the unsigned type. Never overflows.
+			pub fn unsigned_abs(self) -> $uint {
+				if self.is_negative() {
+					self.wrapping_neg().0
+				} else {
+					self.0
+				}
+			}
+
+			/// Computes the absolute difference between self and other.
+			pub fn abs_diff(self, other: $name) -> $uint {
+				if self > other {
+					self.0.overflowing_sub(other.0).0
+				} else {
+					other.0.overflowing_sub(self.0).0
+				}
+			}
+
+			/// Interprets the lowest `bits` bits of `self` as a two's-complement
+			/// number and sign-extends it to the full width of the type.
+			///
+			/// # Panics
+			///
+			/// Panics if `bits` is zero.
+			pub fn sign_extend(self, bits: usize) -> Self {
+				assert!(bits != 0, "cannot sign-extend from zero bits");
+				if bits >= Self::BITS {
+					return self;
+				}
+				let mask = ($uint::one() << bits) - 1u64;
+				if self.0.bit(bits - 1) {
+					$name(self.0 | !mask)
+				} else {
+					$name(self.0 & mask)
+				}
+			}
+
+			/// Negation with overflow. Overflows only for `MIN`.
+			pub fn overflowing_neg(self) -> ($name, bool) {
+				(self.wrapping_neg(), self == Self::MIN)
+			}
+
+			/// Checked negation. Returns `None` if overflow occurred.
+			pub fn checked_neg(self) -> Option<$name> {
+				match self.overflowing_neg() {
+					(_, true) => None,
+					(val, false) => Some(val),
+				}
+			}
+
+			/// Negation which wraps around at the boundary of the type.
+			pub fn wrapping_neg(self) -> $name {
+				$name((!self.0).overflowing_add($uint::one()).0)
+			}
+
+			/// Negation which saturates at `MAX`.
+			pub fn saturating_neg(self) -> $name {
+				match self.overflowing_neg() {
+					(_, true) => Self::MAX,
+					(val, false) => val,
+				}
+			}
+
+			/// Absolute value with overflow. Overflows only for `MIN`.
+			pub fn overflowing_abs(self) -> ($name, bool) {
+				if self.is_negative() {
+					self.overflowing_neg()
+				} else {
+					(self, false)
+				}
+			}
+
+			/// Checked absolute value. Returns `None` if overflow occurred.
+			pub fn checked_abs(self) -> Option<$name> {
+				match self.overflowing_abs() {
+					(_, true) => None,
+					(val, false) => Some(val),
+				}
+			}
+
+			/// Absolute value which wraps around at the boundary of the type.
+			pub fn wrapping_abs(self) -> $name {
+				self.overflowing_abs().0
+			}
+
+			/// Absolute value which saturates at `MAX`.
+			pub fn saturating_abs(self) -> $name {
+				match self.overflowing_abs() {
+					(_, true) => Self::MAX,
+					(val, false) => val,
+				}
+			}
+
+			/// Absolute value.
+			///
+			/// # Panics
+			///
+			/// Panics if `self` is `MIN`.
+			pub fn abs(self) -> $name {
+				let (result, overflow) = self.overflowing_abs();
+				$crate::panic_on_overflow!(overflow);
+				result
+			}
+
+			/// Addition which overflows and returns a flag if it does.
+			#[inline]
+			pub fn overflowing_add(self, other: $name) -> ($name, bool) {
+				let result = $name(self.0.overflowing_add(other.0).0);
+				let overflow = self.is_negative() == other.is_negative() && result.is_negative() != self.is_negative();
+				(result, overflow)
+			}
+
+			/// Checked addition. Returns `None` if overflow occurred.
+			pub fn checked_add(self, other: $name) -> Option<$name> {
+				match self.overflowing_add(other) {
+					(_, true) => None,
+					(val, false) => Some(val),
+				}
+			}
+
+			/// Addition which wraps around at the boundary of the type.
+			pub fn wrapping_add(self, other: $name) -> $name {
+				self.overflowing_add(other).0
+			}
+
+			/// Addition which saturates at `MIN` or `MAX`.
+			pub fn saturating_add(self, other: $name) -> $name {
+				match self.overflowing_add(other) {
+					(_, true) if other.is_negative() => Self::MIN,
+					(_, true) => Self::MAX,
+					(val, false) => val,
+				}
+			}
+
+			/// Subtraction which overflows and returns a flag if it does.
+			#[inline]
+			pub fn overflowing_sub(self, other: $name) -> ($name, bool) {
+				let result = $name(self.0.overflowing_sub(other.0).0);
+				let overflow = self.is_negative() != other.is_negative() && result.is_negative() != self.is_negative();
+				(result, overflow)
+			}
+
+			/// Checked subtraction. Returns `None` if overflow occurred.
+			pub fn checked_sub(self, other: $name) -> Option<$name> {
+				match self.overflowing_sub(other) {
+					(_, true) => None,
+					(val, false) => Some(val),
+				}
+			}
+
+			/// Subtraction which wraps around at the boundary of the type.
+			pub fn wrapping_sub(self, other: $name) -> $name {
+				self.overflowing_sub(other).0
+			}
+
+			/// Subtraction which saturates at `MIN` or `MAX`.
+			pub fn saturating_sub(self, other: $name) -> $name {
+				match self.overflowing_sub(other) {
+					(_, true) if other.is_negative() => Self::MAX,
+					(_, true) => Self::MIN,
+					(val, false) => val,
+				}
+			}
+
+			/// Multiply with overflow, returning a flag if it does.
+			pub fn overflowing_mul(self, other: $name) -> ($name, bool) {
+				// Two's-complement wrapping multiplication is the same as the unsigned one.
+				let result = $name(self.0.overflowing_mul(other.0).0);
+				let (abs, overflow) = self.unsigned_abs().overflowing_mul(other.unsigned_abs());
+				let limit = if self.is_negative() != other.is_negative() { Self::MIN.0 } else { Self::MAX.0 };
+				(result, overflow || abs > limit)
+			}
+
+			/// Checked multiplication. Returns `None` if overflow occurred.
+			pub fn checked_mul(self, other: $name) -> Option<$name> {
+				match self.overflowing_mul(other) {
+					(_, true) => None,
+					(val, false) => Some(val),
+				}
+			}
+
+			/// Multiplication which wraps around at the boundary of the type.
+			pub fn wrapping_mul(self, other: $name) -> $name {
+				self.overflowing_mul(other).0
+			}
+
+			/// Multiplication which saturates at `MIN` or `MAX`.
+			pub fn saturating_mul(self, other: $name) -> $name {
+				match self.overflowing_mul(other) {
+					(_, true) if self.is_negative() != other.is_negative() => Self::MIN,
+					(_, true) => Self::MAX,
+					(val, false) => val,
+				}
+			}
+
+			/// Returns a pair `(self / other, self % other)`, rounding the quotient
+			/// towards zero. The remainder has the sign of `self`.
+			///
+			/// # Panics
+			///
+			/// Panics if `other` is zero or if the quotient overflows, i.e.
+			/// `MIN / -1`.
+			pub fn div_mod(self, other: $name) -> ($name, $name) {
+				let (quotient, overflow) = self.overflowing_div(other);
+				$crate::panic_on_overflow!(overflow);
+				(quotient, self.overflowing_rem(other).0)
+			}
+
+			/// Division with overflow, rounding towards zero. Overflows only for
+			/// `MIN / -1`, in which case `MIN` is returned.
+			///
+			/// # Panics
+			///
+			/// Panics if `other` is zero.
+			pub fn overflowing_div(self, other: $name) -> ($name, bool) {
+				if self == Self::MIN && other == Self::minus_one() {
+					return (Self::MIN, true);
+				}
+				let quotient = $name(self.unsigned_abs() / other.unsigned_abs());
+				if self.is_negative() != other.is_negative() {
+					(quotient.wrapping_neg(), false)
+				} else {
+					(quotient, false)
+				}
+			}
+
+			/// Checked division. Returns `None` if `other == 0` or if overflow
+			/// occurred.
+			pub fn checked_div(self, other: $name) -> Option<$name> {
+				if other.is_zero() {
+					return None;
+				}
+				match self.overflowing_div(other) {
+					(_, true) => None,
+					(val, false) => Some(val),
+				}
+			}
+
+			/// Division which wraps around at the boundary of the type.
+			///
+			/// # Panics
+			///
+			/// Panics if `other` is zero.
+			pub fn wrapping_div(self, other: $name) -> $name {
+				self.overflowing_div(other).0
+			}
+
+			/// Division which saturates at `MAX`.
+			///
+			/// # Panics
+			///
+			/// Panics if `other` is zero.
+			pub fn saturating_div(self, other: $name) -> $name {
+				match self.overflowing_div(other) {
+					(_, true) => Self::MAX,
+					(val, false) => val,
+				}
+			}
+
+			/// Remainder with overflow. The remainder has the sign of `self`.
+			/// Overflows only for `MIN % -1`, in which case zero is returned.
+			///
+			/// # Panics
+			///
+			/// Panics if `other` is zero.
+			pub fn overflowing_rem(self, other: $name) -> ($name, bool) {
+				if self == Self::MIN && other == Self::minus_one() {
+					return (Self::zero(), true);
+				}
+				let remainder = $name(self.unsigned_abs() % other.unsigned_abs());
+				if self.is_negative() {
+					(remainder.wrapping_neg(), false)
+				} else {
+					(remainder, false)
+				}
+			}
+
+			/// Checked remainder. Returns `None` if `other == 0` or if overflow
+			/// occurred.
+			pub fn checked_rem(self, other: $name) -> Option<$name> {
+				if other.is_zero() {
+					return None;
+				}
+				match self.overflowing_rem(other) {
+					(_, true) => None,
+					(val, false) => Some(val),
+				}
+			}
+
+			/// Remainder which wraps around at the boundary of the type.
+			///
+			/// # Panics
+			///
+			/// Panics if `other` is zero.
+			pub fn wrapping_rem(self, other: $name) -> $name {
+				self.overflowing_rem(other).0
+			}
+
+			/// Fast exponentiation by squaring. Returns result and overflow flag.
+			pub fn overflowing_pow(self, expon: u32) -> ($name, bool) {
+				let mut base = self;
+				let mut result = Self::one();
+				let mut expon = expon;
+				let mut overflow = false;
+				while expon > 0 {
+					if expon & 1 == 1 {
+						result = $crate::overflowing!(result.overflowing_mul(base), overflow);
+					}
+					expon >>= 1;
+					if expon > 0 {
+						base = $crate::overflowing!(base.overflowing_mul(base), overflow);
+					}
+				}
+				(result, overflow)
+			}
+
+			/// Checked exponentiation. Returns `None` if overflow occurred.
+			pub fn checked_pow(self, expon: u32) -> Option<$name> {
+				match self.overflowing_pow(expon) {
+					(_, true) => None,
+					(val, false) => Some(val),
+				}
+			}
+
+			/// Exponentiation which wraps around at the boundary of the type.
+			pub fn wrapping_pow(self, expon: u32) -> $name {
+				self.overflowing_pow(expon).0
+			}
+
+			/// Exponentiation which saturates at `MIN` or `MAX`.
+			pub fn saturating_pow(self, expon: u32) -> $name {
+				match self.overflowing_pow(expon) {
+					(_, true) if self.is_negative() && expon % 2 == 1 => Self::MIN,
+					(_, true) => Self::MAX,
+					(val, false) => val,
+				}
+			}
+
+			/// Fast exponentiation by squaring.
+			///
+			/// # Panics
+			///
+			/// Panics if the result overflows the type.
+			pub fn pow(self, expon: u32) -> $name {
+				let (result, overflow) = self.overflowing_pow(expon);
+				$crate::panic_on_overflow!(overflow);
+				result
+			}
+
+			/// Convert from a decimal string with an optional leading `-` or `+` sign.
+			pub fn from_dec_str(value: &str) -> $crate::core_::result::Result<Self, $crate::FromDecStrErr> {
+				let (negative, digits) = match value.as_bytes().first() {
+					Some(b'-') => (true, &value[1..]),
+					Some(b'+') => (false, &value[1..]),
+					_ => (false, value),
+				};
+				if digits.is_empty() && digits.len() != value.len() {
+					return Err($crate::FromDecStrErr::InvalidCharacter);
+				}
+				let abs = $uint::from_dec_str(digits)?;
+				if negative {
+					if abs > Self::MIN.0 {
+						return Err($crate::FromDecStrErr::InvalidLength);
+					}
+					Ok($name(abs).wrapping_neg())
+				} else {
+					if abs > Self::MAX.0 {
+						return Err($crate::FromDecStrErr::InvalidLength);
+					}
+					Ok($name(abs))
+				}
+			}
+		}
+
+		impl $crate::core_::default::Default for $name {
+			fn default() -> Self {
+				$name::zero()
+			}
+		}
+
+		impl $crate::core_::convert::TryFrom<$uint> for $name {
+			type Error = &'static str;
+
+			#[inline]
+			fn try_from(u: $uint) -> $crate::core_::result::Result<$name, &'static str> {
+				if u > $name::MAX.0 {
+					Err(concat!("integer overflow when casting to ", stringify!($name)))
+				} else {
+					Ok($name(u))
+				}
+			}
+		}
+
+		impl $crate::core_::convert::TryFrom<$name> for $uint {
+			type Error = &'static str;
+
+			#[inline]
+			fn try_from(i: $name) -> $crate::core_::result::Result<$uint, &'static str> {
+				if i.is_negative() {
+					Err(concat!("integer underflow when casting to ", stringify!($uint)))
+				} else {
+					Ok(i.0)
+				}
+			}
+		}
+
+		$crate::impl_int_from_signed!($name, $uint, i8, i64, u64);
+		$crate::impl_int_from_signed!($name, $uint, i16, i64, u64);
+		$crate::impl_int_from_signed!($name, $uint, i32, i64, u64);
+		$crate::impl_int_from_signed!($name, $uint, i64, i64, u64);
+		$crate::impl_int_from_signed!($name, $uint, isize, i64, u64);
+		$crate::impl_int_from_signed!($name, $uint, i128, i128, u128);
+		$crate::impl_int_from_unsigned!($name, $uint, u8);
+		$crate::impl_int_from_unsigned!($name, $uint, u16);
+		$crate::impl_int_from_unsigned!($name, $uint, u32);
+		$crate::impl_int_from_unsigned!($name, $uint, u64);
+		$crate::impl_int_from_unsigned!($name, $uint, usize);
+
+		impl $crate::core_::convert::TryFrom<$name> for i128 {
+			type Error = &'static str;
+
+			#[inline]
+			fn try_from(i: $name) -> $crate::core_::result::Result<i128, &'static str> {
+				let low = i.0.low_u128() as i128;
+				if $name::from(low) == i {
+					Ok(low)
+				} else {
+					Err("integer overflow when casting to i128")
+				}
+			}
+		}
+
+		impl $crate::core_::convert::TryFrom<$name> for u128 {
+			type Error = &'static str;
+
+			#[inline]
+			fn try_from(i: $name) -> $crate::core_::result::Result<u128, &'static str> {
+				if i.is_negative() {
+					return Err("integer overflow when casting to u128");
+				}
+				$crate::core_::convert::TryFrom::try_from(i.0)
+			}
+		}
+
+		$crate::impl_try_from_int_for_primitive!($name, i8);
+		$crate::impl_try_from_int_for_primitive!($name, i16);
+		$crate::impl_try_from_int_for_primitive!($name, i32);
+		$crate::impl_try_from_int_for_primitive!($name, i64);
+		$crate::impl_try_from_int_for_primitive!($name, isize);
+		$crate::impl_try_from_int_for_primitive!($name, u8);
+		$crate::impl_try_from_int_for_primitive!($name, u16);
+		$crate::impl_try_from_int_for_primitive!($name, u32);
+		$crate::impl_try_from_int_for_primitive!($name, u64);
+		$crate::impl_try_from_int_for_primitive!($name, usize);
+
+		$crate::impl_int_binop!($name, Add, add, AddAssign, add_assign, overflowing_add);
+		$crate::impl_int_binop!($name, Sub, sub, SubAssign, sub_assign, overflowing_sub);
+		$crate::impl_int_binop!($name, Mul, mul, MulAssign, mul_assign, overflowing_mul);
+		$crate::impl_int_binop!($name, Div, div, DivAssign, div_assign, overflowing_div);
+		$crate::impl_int_binop!($name, Rem, rem, RemAssign, rem_assign, overflowing_rem);
+
+		$crate::impl_int_bitop!($name, BitAnd, bitand, BitAndAssign, bitand_assign);
+		$crate::impl_int_bitop!($name, BitOr, bitor, BitOrAssign, bitor_assign);
+		$crate::impl_int_bitop!($name, BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+		impl $crate::core_::ops::Neg for $name {
+			type Output = $name;
+
+			fn neg(self) -> $name {
+				let (result, overflow) = self.overflowing_neg();
+				$crate::panic_on_overflow!(overflow);
+				result
+			}
+		}
+
+		impl $crate::core_::ops::Not for $name {
+			type Output = $name;
+
+			#[inline]
+			fn not(self) -> $name {
+				$name(!self.0)
+			}
+		}
+
+		impl<T> $crate::core_::ops::Shl<T> for $name where T: Into<$uint> {
+			type Output = $name;
+
+			fn shl(self, shift: T) -> $name {
+				$name(self.0 << shift)
+			}
+		}
+
+		impl<T> $crate::core_::ops::ShlAssign<T> for $name where T: Into<$uint> {
+			fn shl_assign(&mut self, shift: T) {
+				*self = *self << shift;
+			}
+		}
+
+		/// Arithmetic shift: the vacated high bits are filled with the sign bit.
+		impl<T> $crate::core_::ops::Shr<T> for $name where T: Into<$uint> {
+			type Output = $name;
+
+			fn shr(self, shift: T) -> $name {
+				if self.is_negative() {
+					$name(!(!self.0 >> shift))
+				} else {
+					$name(self.0 >> shift)
+				}
+			}
+		}
+
+		impl<T> $crate::core_::ops::ShrAssign<T> for $name where T: Into<$uint> {
+			fn shr_assign(&mut self, shift: T) {
+				*self = *self >> shift;
+			}
+		}
+
+		impl $crate::core_::cmp::Ord for $name {
+			fn cmp(&self, other: &$name) -> $crate::core_::cmp::Ordering {
+				use $crate::core_::cmp::Ordering;
+
+				match (self.is_negative(), other.is_negative()) {
+					(true, false) => Ordering::Less,
+					(false, true) => Ordering::Greater,
+					// Within the same sign, two's-complement order matches unsigned order.
+					_ => self.0.cmp(&other.0),
+				}
+			}
+		}
+
+		impl $crate::core_::cmp::PartialOrd for $name {
+			fn partial_cmp(&self, other: &$name) -> Option<$crate::core_::cmp::Ordering> {
+				Some(self.cmp(other))
+			}
+		}
+
+		impl $crate::core_::fmt::Debug for $name {
+			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
+				$crate::core_::fmt::Display::fmt(self, f)
+			}
+		}
+
+		impl $crate::core_::fmt::Display for $name {
+			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
+				// 3 decimal digits per byte is always enough.
+				let mut buf = [0_u8; $crate::core_::mem::size_of::<$uint>() * 3];
+				let mut i = buf.len();
+				let mut current = self.unsigned_abs();
+				let ten = $uint::from(10u64);
+
+				loop {
+					let (quotient, digit) = current.div_mod(ten);
+					i -= 1;
+					buf[i] = digit.low_u64() as u8 + b'0';
+					current = quotient;
+					if current.is_zero() {
+						break;
+					}
+				}
+
+				// sequence of `'0'..'9'` chars is guaranteed to be a valid UTF8 string
+				let s = unsafe {
+					$crate::core_::str::from_utf8_unchecked(&buf[i..])
+				};
+				f.pad_integral(!self.is_negative(), "", s)
+			}
+		}
+
+		/// Formats the two's-complement bit pattern, like the primitive signed integers do.
+		impl $crate::core_::fmt::LowerHex for $name {
+			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
+				$crate::core_::fmt::LowerHex::fmt(&self.0, f)
+			}
+		}
+
+		/// Formats the two's-complement bit pattern, like the primitive signed integers do.
+		impl $crate::core_::fmt::UpperHex for $name {
+			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
+				$crate::core_::fmt::UpperHex::fmt(&self.0, f)
+			}
+		}
+
+		impl $crate::core_::str::FromStr for $name {
+			type Err = $crate::FromDecStrErr;
+
+			fn from_str(value: &str) -> $crate::core_::result::Result<$name, Self::Err> {
+				Self::from_dec_str(value)
+			}
+		}
+	};
+}
@@ -18,6 +18,10 @@ pub use byteorder;
 #[doc(hidden)]
 pub use core as core_;
 
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use std as std_;
+
 #[doc(hidden)]
 pub use hex;
 
@@ -29,6 +33,18 @@ pub use quickcheck;
 #[doc(hidden)]
 pub use arbitrary;
 
+#[cfg(feature = "zeroize")]
+#[doc(hidden)]
+pub use zeroize;
+
+#[cfg(feature = "subtle")]
+#[doc(hidden)]
+pub use subtle;
+
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+pub use bytemuck;
+
 #[doc(hidden)]
 pub use static_assertions;
 
@@ -38,3 +54,11 @@ pub use crunchy::unroll;
 #[rustfmt::skip]
 mod uint;
 pub use crate::uint::*;
+
+#[macro_use]
+#[rustfmt::skip]
+mod int;
+
+#[macro_use]
+#[rustfmt::skip]
+mod montgomery;
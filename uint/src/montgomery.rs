@@ -0,0 +1,379 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Modular arithmetic in Montgomery form.
+//!
+//! An element `a` is stored as `a * R mod p` where `R = 2^BITS` of the
+//! underlying `construct_uint!` type. Multiplication then only needs a
+//! Montgomery reduction instead of a full division, which makes repeated
+//! arithmetic modulo the same number much cheaper than `mul_mod`.
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_montgomery_binop {
+	($name:ident, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $op:ident) => {
+		impl $crate::core_::ops::$trait<$name> for $name {
+			type Output = $name;
+
+			#[inline]
+			fn $fn(self, other: $name) -> $name {
+				self.$op(&other)
+			}
+		}
+
+		impl<'a> $crate::core_::ops::$trait<&'a $name> for $name {
+			type Output = $name;
+
+			#[inline]
+			fn $fn(self, other: &'a $name) -> $name {
+				self.$op(other)
+			}
+		}
+
+		impl $crate::core_::ops::$assign_trait<$name> for $name {
+			#[inline]
+			fn $assign_fn(&mut self, other: $name) {
+				*self = self.$op(&other);
+			}
+		}
+
+		impl<'a> $crate::core_::ops::$assign_trait<&'a $name> for $name {
+			#[inline]
+			fn $assign_fn(&mut self, other: &'a $name) {
+				*self = self.$op(other);
+			}
+		}
+	};
+}
+
+/// Construct a type for the integers modulo a fixed odd modulus, stored in
+/// Montgomery form.
+///
+/// The first argument is a type created by `construct_uint!`, the second a
+/// constant expression of that type giving the modulus. The modulus must be
+/// odd and greater than one; this is checked at compile time.
+///
+/// # Example
+///
+/// ```
+/// use uint::{construct_montgomery, construct_uint};
+///
+/// construct_uint! {
+///     pub struct U256(4);
+/// }
+///
+/// // 2^255 - 19
+/// const P: U256 = U256([
+///     0xffff_ffff_ffff_ffed,
+///     0xffff_ffff_ffff_ffff,
+///     0xffff_ffff_ffff_ffff,
+///     0x7fff_ffff_ffff_ffff,
+/// ]);
+///
+/// construct_montgomery! {
+///     pub struct Fp(U256, P);
+/// }
+///
+/// let a = Fp::from(3u64);
+/// let b = Fp::from_uint(P - 1);
+/// assert_eq!((a * b).into_uint(), P - 3);
+/// assert_eq!(a * a.inverse().unwrap(), Fp::one());
+/// ```
+#[macro_export]
+macro_rules! construct_montgomery {
+	( $(#[$attr:meta])* $visibility:vis struct $name:ident ( $uint:ident, $modulus:expr ); ) => {
+		/// Element of a fixed-modulus ring, stored in Montgomery form
+		$(#[$attr])*
+		#[derive(Copy, Clone, Eq)]
+		$visibility struct $name ($uint);
+
+		$crate::static_assertions::const_assert!($name::is_valid_modulus());
+
+		impl $name {
+			/// The modulus.
+			pub const MODULUS: $uint = $modulus;
+
+			// -MODULUS^-1 mod 2^64
+			const INV: u64 = {
+				// Newton's iteration doubles the number of correct low bits each step.
+				let p0 = Self::MODULUS.0[0];
+				let mut inv = 1u64;
+				let mut i = 0;
+				while i < 6 {
+					inv = inv.wrapping_mul(2u64.wrapping_sub(p0.wrapping_mul(inv)));
+					i += 1;
+				}
+				inv.wrapping_neg()
+			};
+
+			// R mod MODULUS, the Montgomery form of one.
+			const R: $uint = Self::const_mul_pow2($uint::one(), Self::BITS);
+
+			// R^2 mod MODULUS, used to convert into Montgomery form.
+			const R2: $uint = Self::const_mul_pow2(Self::R, Self::BITS);
+
+			const BITS: usize = $crate::core_::mem::size_of::<$uint>() * 8;
+
+			const fn is_valid_modulus() -> bool {
+				let words = Self::MODULUS.0;
+				let mut high = false;
+				let mut i = 1;
+				while i < words.len() {
+					high |= words[i] != 0;
+					i += 1;
+				}
+				words[0] & 1 == 1 && (high || words[0] > 1)
+			}
+
+			// Computes `value * 2^shift mod MODULUS` by repeated doubling, usable in constants.
+			// `value` must already be reduced.
+			const fn const_mul_pow2(value: $uint, shift: usize) -> $uint {
+				let p = Self::MODULUS.0;
+				let mut words = value.0;
+				let mut n = 0;
+				while n < shift {
+					// double
+					let mut carry = 0u64;
+					let mut i = 0;
+					while i < words.len() {
+						let next = words[i] >> 63;
+						words[i] = (words[i] << 1) | carry;
+						carry = next;
+						i += 1;
+					}
+
+					// subtract the modulus if we went past it
+					let mut ge = carry != 0;
+					if !ge {
+						ge = true;
+						let mut i = words.len();
+						while i > 0 {
+							i -= 1;
+							if words[i] != p[i] {
+								ge = words[i] > p[i];
+								break;
+							}
+						}
+					}
+					if ge {
+						let mut borrow = false;
+						let mut i = 0;
+						while i < words.len() {
+							let (diff, b1) = words[i].overflowing_sub(p[i]);
+							let (diff, b2) = diff.overflowing_sub(borrow as u64);
+							words[i] = diff;
+							borrow = b1 | b2;
+							i += 1;
+						}
+					}
+					n += 1;
+				}
+				$uint(words)
+			}
+
+			/// Zero.
+			#[inline]
+			pub const fn zero() -> Self {
+				$name($uint::zero())
+			}
+
+			/// One.
+			#[inline]
+			pub const fn one() -> Self {
+				$name(Self::R)
+			}
+
+			/// Whether this is zero.
+			#[inline]
+			pub const fn is_zero(&self) -> bool {
+				self.0.is_zero()
+			}
+
+			/// Converts an integer into Montgomery form, reducing it modulo `MODULUS` first.
+			pub fn from_uint(value: $uint) -> Self {
+				let value = if value >= Self::MODULUS { value % Self::MODULUS } else { value };
+				$name(Self::montgomery_mul(&value, &Self::R2))
+			}
+
+			/// Converts back to the canonical integer in `[0, MODULUS)`.
+			pub fn into_uint(self) -> $uint {
+				Self::montgomery_mul(&self.0, &$uint::one())
+			}
+
+			fn add_impl(&self, other: &Self) -> Self {
+				let (sum, overflow) = self.0.overflowing_add(other.0);
+				if overflow || sum >= Self::MODULUS {
+					$name(sum.overflowing_sub(Self::MODULUS).0)
+				} else {
+					$name(sum)
+				}
+			}
+
+			fn sub_impl(&self, other: &Self) -> Self {
+				match self.0.overflowing_sub(other.0) {
+					(diff, true) => $name(diff.overflowing_add(Self::MODULUS).0),
+					(diff, false) => $name(diff),
+				}
+			}
+
+			fn neg_impl(&self) -> Self {
+				if self.is_zero() {
+					*self
+				} else {
+					$name(Self::MODULUS - self.0)
+				}
+			}
+
+			/// Modular doubling.
+			pub fn double(&self) -> Self {
+				self.add_impl(self)
+			}
+
+			fn mul_impl(&self, other: &Self) -> Self {
+				$name(Self::montgomery_mul(&self.0, &other.0))
+			}
+
+			/// Modular squaring.
+			pub fn square(&self) -> Self {
+				self.mul_impl(self)
+			}
+
+			/// Raises `self` to the power of `expon` by squaring.
+			pub fn pow(&self, expon: $uint) -> Self {
+				let mut result = Self::one();
+				let mut base = *self;
+				for i in 0..expon.bits() {
+					if expon.bit(i) {
+						result = result.mul_impl(&base);
+					}
+					base = base.square();
+				}
+				result
+			}
+
+			/// Multiplicative inverse, or `None` if `self` is not invertible.
+			pub fn inverse(&self) -> Option<Self> {
+				self.into_uint().inv_mod(Self::MODULUS).map(Self::from_uint)
+			}
+
+			/// Equality check whose running time does not depend on the values compared.
+			pub fn ct_eq_bool(&self, other: &Self) -> bool {
+				self.0.ct_eq_bool(&other.0)
+			}
+
+			// Montgomery multiplication (CIOS): computes `a * b / R mod MODULUS`.
+			// Both `a` and `b` must be reduced.
+			fn montgomery_mul(a: &$uint, b: &$uint) -> $uint {
+				let p = &Self::MODULUS.0;
+				let n = p.len();
+				let mut t = $uint::zero();
+				// the two words above t
+				let mut t_n = 0u64;
+
+				for i in 0..n {
+					// t += a * b[i]
+					let mut carry = 0u64;
+					for j in 0..n {
+						let x = u128::from(t.0[j]) + u128::from(a.0[j]) * u128::from(b.0[i]) + u128::from(carry);
+						t.0[j] = x as u64;
+						carry = (x >> 64) as u64;
+					}
+					let x = u128::from(t_n) + u128::from(carry);
+					t_n = x as u64;
+					let t_n1 = (x >> 64) as u64;
+
+					// t = (t + m * p) / 2^64, with m chosen so the division is exact
+					let m = t.0[0].wrapping_mul(Self::INV);
+					let x = u128::from(t.0[0]) + u128::from(m) * u128::from(p[0]);
+					let mut carry = (x >> 64) as u64;
+					for j in 1..n {
+						let x = u128::from(t.0[j]) + u128::from(m) * u128::from(p[j]) + u128::from(carry);
+						t.0[j - 1] = x as u64;
+						carry = (x >> 64) as u64;
+					}
+					let x = u128::from(t_n) + u128::from(carry);
+					t.0[n - 1] = x as u64;
+					t_n = t_n1 + (x >> 64) as u64;
+				}
+
+				if t_n != 0 || t >= Self::MODULUS {
+					t.overflowing_sub(Self::MODULUS).0
+				} else {
+					t
+				}
+			}
+		}
+
+		impl $crate::core_::default::Default for $name {
+			fn default() -> Self {
+				$name::zero()
+			}
+		}
+
+		impl $crate::core_::cmp::PartialEq for $name {
+			fn eq(&self, other: &Self) -> bool {
+				self.ct_eq_bool(other)
+			}
+		}
+
+		impl $crate::core_::hash::Hash for $name {
+			fn hash<H: $crate::core_::hash::Hasher>(&self, state: &mut H) {
+				self.0.hash(state);
+			}
+		}
+
+		impl $crate::core_::convert::From<$uint> for $name {
+			fn from(value: $uint) -> Self {
+				$name::from_uint(value)
+			}
+		}
+
+		impl $crate::core_::convert::From<u64> for $name {
+			fn from(value: u64) -> Self {
+				$name::from_uint($uint::from(value))
+			}
+		}
+
+		impl $crate::core_::convert::From<$name> for $uint {
+			fn from(value: $name) -> $uint {
+				value.into_uint()
+			}
+		}
+
+		$crate::impl_montgomery_binop!($name, Add, add, AddAssign, add_assign, add_impl);
+		$crate::impl_montgomery_binop!($name, Sub, sub, SubAssign, sub_assign, sub_impl);
+		$crate::impl_montgomery_binop!($name, Mul, mul, MulAssign, mul_assign, mul_impl);
+
+		impl $crate::core_::ops::Neg for $name {
+			type Output = $name;
+
+			fn neg(self) -> $name {
+				self.neg_impl()
+			}
+		}
+
+		impl $crate::core_::fmt::Debug for $name {
+			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
+				$crate::core_::fmt::Display::fmt(self, f)
+			}
+		}
+
+		/// Formats the canonical integer, not the Montgomery form.
+		impl $crate::core_::fmt::Display for $name {
+			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
+				$crate::core_::fmt::Display::fmt(&self.into_uint(), f)
+			}
+		}
+
+		impl $crate::core_::fmt::LowerHex for $name {
+			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
+				$crate::core_::fmt::LowerHex::fmt(&self.into_uint(), f)
+			}
+		}
+	};
+}
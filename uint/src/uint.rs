@@ -73,6 +73,16 @@ impl FromStrRadixErr {
 		Self { kind: FromStrRadixErrKind::UnsupportedRadix, source: None }
 	}
 
+	#[doc(hidden)]
+	pub fn invalid_character() -> Self {
+		Self { kind: FromStrRadixErrKind::InvalidCharacter, source: None }
+	}
+
+	#[doc(hidden)]
+	pub fn invalid_length() -> Self {
+		Self { kind: FromStrRadixErrKind::InvalidLength, source: None }
+	}
+
 	/// Returns the corresponding `FromStrRadixErrKind` for this error.
 	pub fn kind(&self) -> FromStrRadixErrKind {
 		self.kind
@@ -575,12 +585,28 @@ macro_rules! construct_uint {
 			/// Maximum value.
 			pub const MAX: $name = $name([u64::max_value(); $n_words]);
 
-			/// Converts a string slice in a given base to an integer. Only supports radixes of 10
-			/// and 16.
+			/// Converts a string slice in a given base to an integer. Supports radixes from 2 to 36;
+			/// digits above 9 are letters in either case. Only base 16 accepts a `0x` prefix.
 			pub fn from_str_radix(txt: &str, radix: u32) -> Result<Self, $crate::FromStrRadixErr> {
 				let parsed = match radix {
 					10 => Self::from_dec_str(txt)?,
 					16 => core::str::FromStr::from_str(txt)?,
+					2..=36 => {
+						let mut res = Self::zero();
+						for c in txt.chars() {
+							let digit = c.to_digit(radix).ok_or_else($crate::FromStrRadixErr::invalid_character)?;
+							let (r, overflow) = res.overflowing_mul_u64(radix as u64);
+							if overflow > 0 {
+								return Err($crate::FromStrRadixErr::invalid_length());
+							}
+							let (r, overflow) = r.overflowing_add(digit.into());
+							if overflow {
+								return Err($crate::FromStrRadixErr::invalid_length());
+							}
+							res = r;
+						}
+						res
+					},
 					_ => return Err($crate::FromStrRadixErr::unsupported()),
 				};
 
@@ -672,6 +698,17 @@ macro_rules! construct_uint {
 				return true;
 			}
 
+			/// Returns `true` if `self` and `other` are equal, taking the same time
+			/// whatever their values. Use this instead of `==` for secrets.
+			#[inline]
+			pub fn ct_eq_bool(&self, other: &Self) -> bool {
+				let diff = self.0.iter().zip(other.0.iter()).fold(0u64, |acc, (a, b)| acc | (a ^ b));
+				// keep the compiler from turning the fold into an early-exit comparison
+				// SAFETY: `diff` is a local, so the pointer is valid, aligned and
+				// points to an initialized value.
+				unsafe { $crate::core_::ptr::read_volatile(&diff) == 0 }
+			}
+
 			// Whether this fits u64.
 			#[inline]
 			fn fits_word(&self) -> bool {
@@ -797,6 +834,206 @@ macro_rules! construct_uint {
 				Self::MAX
 			}
 
+			/// Converts from a `u64`. Usable in constants, unlike `From<u64>`.
+			#[inline]
+			pub const fn from_u64(value: u64) -> Self {
+				let mut words = [0; $n_words];
+				words[0] = value;
+				Self(words)
+			}
+
+			/// Parses a decimal string, or a hexadecimal one if prefixed with `0x`.
+			/// Underscores are allowed as digit separators.
+			///
+			/// Meant for constants, where a malformed literal becomes a compile error:
+			/// `const X: U256 = U256::from_str_const("0x8000_0000");`
+			///
+			/// # Panics
+			///
+			/// Panics if the string is empty, contains an invalid character or the number
+			/// does not fit.
+			pub const fn from_str_const(txt: &str) -> Self {
+				let bytes = txt.as_bytes();
+				let (radix, mut i) = if bytes.len() >= 2 && bytes[0] == b'0' && (bytes[1] == b'x' || bytes[1] == b'X') {
+					(16, 2)
+				} else {
+					(10, 0)
+				};
+
+				let mut ret = Self::zero();
+				let mut seen_digit = false;
+				while i < bytes.len() {
+					let digit = match bytes[i] {
+						b'_' => {
+							i += 1;
+							continue;
+						},
+						b @ b'0'..=b'9' => b - b'0',
+						b @ b'a'..=b'f' if radix == 16 => b - b'a' + 10,
+						b @ b'A'..=b'F' if radix == 16 => b - b'A' + 10,
+						_ => panic!("invalid character"),
+					};
+					let (shifted, overflow1) = ret.const_overflowing_mul(Self::from_u64(radix));
+					let (sum, overflow2) = shifted.const_overflowing_add(Self::from_u64(digit as u64));
+					assert!(!overflow1 && !overflow2, "number too large");
+					ret = sum;
+					seen_digit = true;
+					i += 1;
+				}
+				assert!(seen_digit, "empty number");
+				ret
+			}
+
+			/// Addition with overflow, usable in constants.
+			pub const fn const_overflowing_add(self, other: Self) -> (Self, bool) {
+				let mut ret = [0u64; $n_words];
+				let mut carry = false;
+				let mut i = 0;
+				while i < $n_words {
+					let (sum, c1) = self.0[i].overflowing_add(other.0[i]);
+					let (sum, c2) = sum.overflowing_add(carry as u64);
+					ret[i] = sum;
+					carry = c1 | c2;
+					i += 1;
+				}
+				(Self(ret), carry)
+			}
+
+			/// Subtraction with overflow, usable in constants.
+			pub const fn const_overflowing_sub(self, other: Self) -> (Self, bool) {
+				let mut ret = [0u64; $n_words];
+				let mut borrow = false;
+				let mut i = 0;
+				while i < $n_words {
+					let (diff, b1) = self.0[i].overflowing_sub(other.0[i]);
+					let (diff, b2) = diff.overflowing_sub(borrow as u64);
+					ret[i] = diff;
+					borrow = b1 | b2;
+					i += 1;
+				}
+				(Self(ret), borrow)
+			}
+
+			/// Multiplication with overflow, usable in constants.
+			pub const fn const_overflowing_mul(self, other: Self) -> (Self, bool) {
+				let mut ret = [0u64; $n_words];
+				let mut overflow = false;
+				let mut i = 0;
+				while i < $n_words {
+					let b = other.0[i];
+					let mut carry = 0u64;
+					let mut j = 0;
+					while i + j < $n_words {
+						let x = ret[i + j] as u128 + self.0[j] as u128 * b as u128 + carry as u128;
+						ret[i + j] = x as u64;
+						carry = (x >> 64) as u64;
+						j += 1;
+					}
+					overflow |= carry != 0;
+					// the rest of the products land above the top word
+					while j < $n_words {
+						overflow |= self.0[j] != 0 && b != 0;
+						j += 1;
+					}
+					i += 1;
+				}
+				(Self(ret), overflow)
+			}
+
+			/// Addition usable in constants.
+			///
+			/// # Panics
+			///
+			/// Panics if the result overflows the type.
+			pub const fn const_add(self, other: Self) -> Self {
+				let (ret, overflow) = self.const_overflowing_add(other);
+				assert!(!overflow, "arithmetic operation overflow");
+				ret
+			}
+
+			/// Subtraction usable in constants.
+			///
+			/// # Panics
+			///
+			/// Panics if the result underflows the type.
+			pub const fn const_sub(self, other: Self) -> Self {
+				let (ret, overflow) = self.const_overflowing_sub(other);
+				assert!(!overflow, "arithmetic operation overflow");
+				ret
+			}
+
+			/// Multiplication usable in constants.
+			///
+			/// # Panics
+			///
+			/// Panics if the result overflows the type.
+			pub const fn const_mul(self, other: Self) -> Self {
+				let (ret, overflow) = self.const_overflowing_mul(other);
+				assert!(!overflow, "arithmetic operation overflow");
+				ret
+			}
+
+			/// Left shift usable in constants. Shifting by the bit width or more gives zero,
+			/// like the `<<` operator.
+			pub const fn const_shl(self, shift: usize) -> Self {
+				let mut ret = [0u64; $n_words];
+				let word_shift = shift / 64;
+				let bit_shift = shift % 64;
+				let mut i = word_shift;
+				while i < $n_words {
+					ret[i] = self.0[i - word_shift] << bit_shift;
+					if bit_shift > 0 && i > word_shift {
+						ret[i] |= self.0[i - 1 - word_shift] >> (64 - bit_shift);
+					}
+					i += 1;
+				}
+				Self(ret)
+			}
+
+			/// Right shift usable in constants. Shifting by the bit width or more gives zero,
+			/// like the `>>` operator.
+			pub const fn const_shr(self, shift: usize) -> Self {
+				let mut ret = [0u64; $n_words];
+				let word_shift = shift / 64;
+				let bit_shift = shift % 64;
+				let mut i = word_shift;
+				while i < $n_words {
+					ret[i - word_shift] = self.0[i] >> bit_shift;
+					if bit_shift > 0 && i + 1 < $n_words {
+						ret[i - word_shift] |= self.0[i + 1] << (64 - bit_shift);
+					}
+					i += 1;
+				}
+				Self(ret)
+			}
+
+			/// Comparison usable in constants.
+			pub const fn const_cmp(&self, other: &Self) -> $crate::core_::cmp::Ordering {
+				let mut i = $n_words;
+				while i > 0 {
+					i -= 1;
+					if self.0[i] < other.0[i] {
+						return $crate::core_::cmp::Ordering::Less;
+					}
+					if self.0[i] > other.0[i] {
+						return $crate::core_::cmp::Ordering::Greater;
+					}
+				}
+				$crate::core_::cmp::Ordering::Equal
+			}
+
+			/// Equality usable in constants.
+			pub const fn const_eq(&self, other: &Self) -> bool {
+				let mut i = 0;
+				while i < $n_words {
+					if self.0[i] != other.0[i] {
+						return false;
+					}
+					i += 1;
+				}
+				true
+			}
+
 			fn full_shl(self, shift: u32) -> [u64; $n_words + 1] {
 				debug_assert!(shift < Self::WORD_BITS as u32);
 				let mut u = [0u64; $n_words + 1];
@@ -855,6 +1092,19 @@ macro_rules! construct_uint {
 
 				// quotient
 				let mut q = Self::zero();
+				Self::div_mod_knuth_loop(&mut u, &v, n, m, &mut q.0);
+
+				// D8.
+				let remainder = Self::full_shr(u, shift);
+
+				(q, remainder)
+			}
+
+			// Steps D2 to D7 of `div_mod_knuth`, shared with `rem_wide`. Divides the
+			// shifted numerator `u` of `n + m + 1` words by the shifted `v` of `n`
+			// words, writing the quotient to `q` and leaving the shifted remainder in `u`.
+			fn div_mod_knuth_loop(u: &mut [u64], v: &Self, n: usize, m: usize, q: &mut [u64]) {
+				debug_assert!(u.len() > n + m && q.len() > m);
 				let v_n_1 = v.0[n - 1];
 				let v_n_2 = v.0[n - 2];
 
@@ -914,13 +1164,8 @@ macro_rules! construct_uint {
 					}
 
 					// D5.
-					q.0[j] = q_hat;
+					q[j] = q_hat;
 				}
-
-				// D8.
-				let remainder = Self::full_shr(u, shift);
-
-				(q, remainder)
 			}
 
 			// Returns the least number of words needed to represent the nonzero number
@@ -982,6 +1227,235 @@ macro_rules! construct_uint {
 				}
 			}
 
+			/// Compute the highest `r` such that `r^n <= self`.
+			///
+			/// # Panics
+			///
+			/// Panics if `n` is zero.
+			pub fn integer_nth_root(&self, n: u32) -> Self {
+				assert!(n != 0, "zeroth root is undefined");
+				let one = Self::one();
+				let bits = self.bits();
+				if n == 1 || self <= &one {
+					return *self;
+				}
+				// 2^n > self, so the root is 1
+				if n as usize >= bits {
+					return one;
+				}
+
+				// Newton's method, starting from a guess higher than the root:
+				// x' = ((n - 1) * x + self / x^(n - 1)) / n
+				let shift = (bits + n as usize - 1) / n as usize;
+				let mut x_prev = one << shift;
+				let n_minus_1 = Self::from(n - 1);
+				loop {
+					let quotient = match x_prev.checked_pow(n_minus_1) {
+						Some(p) => *self / p,
+						None => Self::zero(),
+					};
+					let x = (x_prev * n_minus_1 + quotient) / n;
+					if x >= x_prev {
+						return x_prev;
+					}
+					x_prev = x;
+				}
+			}
+
+			/// Greatest common divisor. `gcd(0, 0)` is zero.
+			pub fn gcd(self, other: Self) -> Self {
+				// Stein's binary algorithm
+				let (mut a, mut b) = (self, other);
+				if a.is_zero() {
+					return b;
+				}
+				if b.is_zero() {
+					return a;
+				}
+				let shift = (a | b).trailing_zeros();
+				a >>= a.trailing_zeros();
+				loop {
+					b >>= b.trailing_zeros();
+					if a > b {
+						$crate::core_::mem::swap(&mut a, &mut b);
+					}
+					b -= a;
+					if b.is_zero() {
+						return a << shift;
+					}
+				}
+			}
+
+			/// Least common multiple. The result is zero if either argument is zero.
+			///
+			/// # Panics
+			///
+			/// Panics if the result overflows the type.
+			pub fn lcm(self, other: Self) -> Self {
+				match self.checked_lcm(other) {
+					Some(lcm) => lcm,
+					None => panic!("arithmetic operation overflow"),
+				}
+			}
+
+			/// Checked least common multiple. Returns `None` if overflow occurred.
+			pub fn checked_lcm(self, other: Self) -> Option<Self> {
+				if self.is_zero() || other.is_zero() {
+					return Some(Self::zero());
+				}
+				(self / self.gcd(other)).checked_mul(other)
+			}
+
+			/// Returns the logarithm of `self` with respect to `base`, rounded down.
+			///
+			/// # Panics
+			///
+			/// Panics if `self` is zero or `base` is less than 2.
+			pub fn ilog(self, base: Self) -> u32 {
+				assert!(base >= Self::from(2u64), "base of integer logarithm must be at least 2");
+				match self.checked_ilog(base) {
+					Some(log) => log,
+					None => panic!("argument of integer logarithm must be positive"),
+				}
+			}
+
+			/// Returns the base 2 logarithm of `self`, rounded down.
+			///
+			/// # Panics
+			///
+			/// Panics if `self` is zero.
+			pub fn ilog2(self) -> u32 {
+				match self.checked_ilog2() {
+					Some(log) => log,
+					None => panic!("argument of integer logarithm must be positive"),
+				}
+			}
+
+			/// Returns the base 10 logarithm of `self`, rounded down.
+			///
+			/// # Panics
+			///
+			/// Panics if `self` is zero.
+			pub fn ilog10(self) -> u32 {
+				match self.checked_ilog10() {
+					Some(log) => log,
+					None => panic!("argument of integer logarithm must be positive"),
+				}
+			}
+
+			/// Returns the logarithm of `self` with respect to `base`, rounded down,
+			/// or `None` if `self` is zero or `base` is less than 2.
+			pub fn checked_ilog(self, base: Self) -> Option<u32> {
+				if self.is_zero() || base < Self::from(2u64) {
+					return None;
+				}
+				if base.is_power_of_two() {
+					return Some(self.ilog2() / base.ilog2());
+				}
+				let mut n = 0;
+				let mut rest = self;
+				while rest >= base {
+					rest /= base;
+					n += 1;
+				}
+				Some(n)
+			}
+
+			/// Returns the base 2 logarithm of `self`, rounded down, or `None` if `self` is zero.
+			pub fn checked_ilog2(self) -> Option<u32> {
+				match self.bits() {
+					0 => None,
+					bits => Some(bits as u32 - 1),
+				}
+			}
+
+			/// Returns the base 10 logarithm of `self`, rounded down, or `None` if `self` is zero.
+			pub fn checked_ilog10(self) -> Option<u32> {
+				if self.is_zero() {
+					return None;
+				}
+				// Strip 19 digits at a time, the largest power of ten fitting in a word.
+				const TEN_19: u64 = 10_000_000_000_000_000_000;
+				let mut n = 0;
+				let mut rest = self;
+				while !rest.fits_word() || rest.low_u64() >= TEN_19 {
+					rest = rest.div_mod_small(TEN_19).0;
+					n += 19;
+				}
+				let mut rest = rest.low_u64();
+				while rest >= 10 {
+					rest /= 10;
+					n += 1;
+				}
+				Some(n)
+			}
+
+			/// Whether `self` is a power of two.
+			pub fn is_power_of_two(&self) -> bool {
+				self.count_ones() == 1
+			}
+
+			/// Returns the smallest power of two greater than or equal to `self`.
+			///
+			/// # Panics
+			///
+			/// Panics if the result overflows the type.
+			pub fn next_power_of_two(self) -> Self {
+				match self.checked_next_power_of_two() {
+					Some(p) => p,
+					None => panic!("arithmetic operation overflow"),
+				}
+			}
+
+			/// Returns the smallest power of two greater than or equal to `self`, or `None`
+			/// if it overflows the type.
+			pub fn checked_next_power_of_two(self) -> Option<Self> {
+				if self <= Self::one() {
+					return Some(Self::one());
+				}
+				let bits = (self - 1).bits();
+				if bits == Self::WORD_BITS * $n_words {
+					None
+				} else {
+					Some(Self::one() << bits)
+				}
+			}
+
+			/// Returns the number of ones in the binary representation of `self`.
+			pub fn count_ones(&self) -> u32 {
+				self.0.iter().map(|w| w.count_ones()).sum()
+			}
+
+			/// Returns the number of zeros in the binary representation of `self`.
+			pub fn count_zeros(&self) -> u32 {
+				self.0.iter().map(|w| w.count_zeros()).sum()
+			}
+
+			/// Shifts the bits to the left by `n`, wrapping the truncated bits to the end.
+			pub fn rotate_left(self, n: u32) -> Self {
+				let bits = (Self::WORD_BITS * $n_words) as u32;
+				let n = n % bits;
+				if n == 0 {
+					return self;
+				}
+				(self << n) | (self >> (bits - n))
+			}
+
+			/// Shifts the bits to the right by `n`, wrapping the truncated bits to the beginning.
+			pub fn rotate_right(self, n: u32) -> Self {
+				let bits = (Self::WORD_BITS * $n_words) as u32;
+				self.rotate_left(bits - n % bits)
+			}
+
+			/// Reverses the order of bits, so the least significant bit becomes the most significant.
+			pub fn reverse_bits(self) -> Self {
+				let mut ret = [0u64; $n_words];
+				for i in 0..$n_words {
+					ret[$n_words - 1 - i] = self.0[i].reverse_bits();
+				}
+				$name(ret)
+			}
+
 			/// Fast exponentiation by squaring
 			/// https://en.wikipedia.org/wiki/Exponentiation_by_squaring
 			///
@@ -1118,6 +1592,18 @@ macro_rules! construct_uint {
 				$crate::uint_overflowing_mul!($name, $n_words, self, other)
 			}
 
+			/// Multiplies two integers, returning the full double-width product as a pair
+			/// `(low, high)`. Overflow is not possible.
+			#[inline(always)]
+			pub fn widening_mul(self, other: $name) -> ($name, $name) {
+				let ret: [u64; $n_words * 2] = $crate::uint_full_mul_reg!($name, $n_words, self, other);
+				let mut low = [0u64; $n_words];
+				let mut high = [0u64; $n_words];
+				low.copy_from_slice(&ret[..$n_words]);
+				high.copy_from_slice(&ret[$n_words..]);
+				($name(low), $name(high))
+			}
+
 			/// Multiplication which saturates at the maximum value..
 			pub fn saturating_mul(self, other: $name) -> $name {
 				match self.overflowing_mul(other) {
@@ -1169,6 +1655,127 @@ macro_rules! construct_uint {
 				}
 			}
 
+			/// Modular addition. Computes `(self + other) % modulus` without overflowing.
+			///
+			/// # Panics
+			///
+			/// Panics if `modulus` is zero.
+			pub fn add_mod(self, other: $name, modulus: $name) -> $name {
+				let a = self % modulus;
+				let b = other % modulus;
+				let (sum, overflow) = a.overflowing_add(b);
+				if overflow || sum >= modulus {
+					sum.overflowing_sub(modulus).0
+				} else {
+					sum
+				}
+			}
+
+			/// Modular subtraction. Computes `(self - other) mod modulus`, the result
+			/// always being in the range `[0, modulus)`.
+			///
+			/// # Panics
+			///
+			/// Panics if `modulus` is zero.
+			pub fn sub_mod(self, other: $name, modulus: $name) -> $name {
+				let a = self % modulus;
+				let b = other % modulus;
+				match a.overflowing_sub(b) {
+					(diff, true) => diff.overflowing_add(modulus).0,
+					(diff, false) => diff,
+				}
+			}
+
+			/// Modular multiplication. Computes `(self * other) % modulus` using the
+			/// full double-width product, so it never overflows.
+			///
+			/// # Panics
+			///
+			/// Panics if `modulus` is zero.
+			pub fn mul_mod(self, other: $name, modulus: $name) -> $name {
+				let wide: [u64; $n_words * 2] = $crate::uint_full_mul_reg!($name, $n_words, self, other);
+				Self::rem_wide(wide, modulus)
+			}
+
+			/// Modular exponentiation by squaring. Computes `self.pow(expon) % modulus`.
+			///
+			/// # Panics
+			///
+			/// Panics if `modulus` is zero.
+			pub fn pow_mod(self, expon: $name, modulus: $name) -> $name {
+				let mut result = Self::one() % modulus;
+				let mut base = self % modulus;
+				for i in 0..expon.bits() {
+					if expon.bit(i) {
+						result = result.mul_mod(base, modulus);
+					}
+					base = base.mul_mod(base, modulus);
+				}
+				result
+			}
+
+			/// Modular multiplicative inverse. Returns `x` such that `(self * x) % modulus == 1 % modulus`,
+			/// or `None` if `self` and `modulus` are not coprime.
+			///
+			/// # Panics
+			///
+			/// Panics if `modulus` is zero.
+			pub fn inv_mod(self, modulus: $name) -> Option<$name> {
+				// Extended Euclidean algorithm, keeping the Bezout coefficient reduced modulo `modulus`.
+				let (mut r0, mut r1) = (modulus, self % modulus);
+				let (mut t0, mut t1) = (Self::zero(), Self::one() % modulus);
+				while !r1.is_zero() {
+					let (q, r) = r0.div_mod(r1);
+					r0 = r1;
+					r1 = r;
+					let t = t0.sub_mod(q.mul_mod(t1, modulus), modulus);
+					t0 = t1;
+					t1 = t;
+				}
+				if r0 == Self::one() {
+					Some(t0)
+				} else {
+					None
+				}
+			}
+
+			// Remainder of a double-width little-endian number modulo `v`.
+			fn rem_wide(wide: [u64; $n_words * 2], mut v: Self) -> Self {
+				let your_bits = v.bits();
+				assert!(your_bits != 0, "division by zero");
+
+				if your_bits <= Self::WORD_BITS {
+					let v = v.low_u64();
+					let rem = wide.iter().rev().fold(0u64, |rem, d| Self::div_mod_word(rem, *d, v).1);
+					return rem.into();
+				}
+
+				// D1., as in `div_mod_knuth` but on the double-width number.
+				let n = Self::words(your_bits);
+				let m = $n_words * 2 - n;
+				let shift = v.0[n - 1].leading_zeros();
+				v <<= shift;
+
+				// u = wide << shift
+				let mut u = [0u64; $n_words * 2 + 1];
+				u[..$n_words * 2].copy_from_slice(&wide[..]);
+				if shift > 0 {
+					for i in (1..=$n_words * 2).rev() {
+						u[i] = (u[i] << shift) | (u[i - 1] >> (Self::WORD_BITS as u32 - shift));
+					}
+					u[0] <<= shift;
+				}
+
+				// the quotient is not needed
+				let mut q = [0u64; $n_words * 2];
+				Self::div_mod_knuth_loop(&mut u, &v, n, m, &mut q);
+
+				// the remainder is less than v, so it fits in the low words
+				let mut rem = [0u64; $n_words + 1];
+				rem.copy_from_slice(&u[..$n_words + 1]);
+				Self::full_shr(rem, shift)
+			}
+
 			#[inline(always)]
 			fn div_mod_word(hi: u64, lo: u64, y: u64) -> (u64, u64) {
 				debug_assert!(hi < y);
@@ -1237,32 +1844,29 @@ macro_rules! construct_uint {
 			}
 
 			/// Converts from big endian representation bytes in memory.
-			pub fn from_big_endian(slice: &[u8]) -> Self {
-				use $crate::byteorder::{ByteOrder, BigEndian};
+			pub const fn from_big_endian(slice: &[u8]) -> Self {
 				assert!($n_words * 8 >= slice.len());
 
-				let mut padded = [0u8; $n_words * 8];
-				padded[$n_words * 8 - slice.len() .. $n_words * 8].copy_from_slice(&slice);
-
 				let mut ret = [0; $n_words];
-				for i in 0..$n_words {
-					ret[$n_words - i - 1] = BigEndian::read_u64(&padded[8 * i..]);
+				let mut i = 0;
+				while i < slice.len() {
+					let byte = slice[slice.len() - 1 - i];
+					ret[i / 8] |= (byte as u64) << (i % 8 * 8);
+					i += 1;
 				}
 
 				$name(ret)
 			}
 
 			/// Converts from little endian representation bytes in memory.
-			pub fn from_little_endian(slice: &[u8]) -> Self {
-				use $crate::byteorder::{ByteOrder, LittleEndian};
+			pub const fn from_little_endian(slice: &[u8]) -> Self {
 				assert!($n_words * 8 >= slice.len());
 
-				let mut padded = [0u8; $n_words * 8];
-				padded[0..slice.len()].copy_from_slice(&slice);
-
 				let mut ret = [0; $n_words];
-				for i in 0..$n_words {
-					ret[i] = LittleEndian::read_u64(&padded[8 * i..]);
+				let mut i = 0;
+				while i < slice.len() {
+					ret[i / 8] |= (slice[i] as u64) << (i % 8 * 8);
+					i += 1;
 				}
 
 				$name(ret)
@@ -1305,6 +1909,54 @@ macro_rules! construct_uint {
 				};
 				f.pad_integral(true, "0x", s)
 			}
+
+			// Writes the digits of `self` in the given radix to the end of `buf`, using lowercase
+			// letters above 9, and returns the index of the first digit.
+			fn write_radix(&self, buf: &mut [u8; $n_words * 64], radix: u32) -> usize {
+				assert!((2..=36).contains(&radix), "radix must be in the range 2..=36");
+				let radix = radix as u64;
+
+				// Divide by the largest power of the radix that fits in a word, so that each
+				// division yields several digits at once.
+				let mut chunk = radix;
+				let mut chunk_digits = 1;
+				while let Some(next) = chunk.checked_mul(radix) {
+					chunk = next;
+					chunk_digits += 1;
+				}
+
+				let mut i = buf.len();
+				let mut current = *self;
+				loop {
+					let (q, r) = current.div_mod_small(chunk);
+					let mut r = r.low_u64();
+					for _ in 0..chunk_digits {
+						// no leading zeros in the most significant chunk
+						if q.is_zero() && r == 0 && i < buf.len() {
+							break;
+						}
+						let digit = (r % radix) as u8;
+						i -= 1;
+						buf[i] = if digit < 10 { digit + b'0' } else { digit - 10 + b'a' };
+						r /= radix;
+					}
+					current = q;
+					if current.is_zero() {
+						break;
+					}
+				}
+				i
+			}
+
+			fn fmt_radix(&self, f: &mut $crate::core_::fmt::Formatter, radix: u32, prefix: &str) -> $crate::core_::fmt::Result {
+				let mut buf = [0_u8; $n_words * 64];
+				let i = self.write_radix(&mut buf, radix);
+				// sequence of `'0'..'9' 'a'..'z'` chars is guaranteed to be a valid UTF8 string
+				let s = unsafe {
+					$crate::core_::str::from_utf8_unchecked(&buf[i..])
+				};
+				f.pad_integral(true, prefix, s)
+			}
 		}
 
 		impl $crate::core_::convert::From<$name> for [u8; $n_words * 8] {
@@ -1705,6 +2357,18 @@ macro_rules! construct_uint {
 			}
 		}
 
+		impl $crate::core_::fmt::Binary for $name {
+			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
+				self.fmt_radix(f, 2, "0b")
+			}
+		}
+
+		impl $crate::core_::fmt::Octal for $name {
+			fn fmt(&self, f: &mut $crate::core_::fmt::Formatter) -> $crate::core_::fmt::Result {
+				self.fmt_radix(f, 8, "0o")
+			}
+		}
+
 		impl $crate::core_::str::FromStr for $name {
 			type Err = $crate::FromHexError;
 
@@ -1747,6 +2411,11 @@ macro_rules! construct_uint {
 			}
 		}
 
+		$crate::impl_std_for_uint!($name, $n_words);
+		$crate::impl_zeroize_for_uint!($name);
+		$crate::impl_subtle_for_uint!($name);
+		$crate::impl_bytemuck_for_uint!($name);
+
 		// `$n_words * 8` because macro expects bytes and
 		// uints use 64 bit (8 byte) words
 		$crate::impl_quickcheck_arbitrary_for_uint!($name, ($n_words * 8));
@@ -1754,6 +2423,108 @@ macro_rules! construct_uint {
 	}
 }
 
+#[cfg(feature = "std")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_std_for_uint {
+	($name: ident, $n_words: tt) => {
+		impl $name {
+			/// Converts the number to a string in the given base, using lowercase letters for
+			/// digits above 9.
+			///
+			/// # Panics
+			///
+			/// Panics if `radix` is not in the range `2..=36`.
+			pub fn to_str_radix(&self, radix: u32) -> $crate::std_::string::String {
+				let mut buf = [0_u8; $n_words * 64];
+				let i = self.write_radix(&mut buf, radix);
+				// sequence of `'0'..'9' 'a'..'z'` chars is guaranteed to be a valid UTF8 string
+				unsafe { $crate::std_::string::String::from_utf8_unchecked(buf[i..].to_vec()) }
+			}
+		}
+	};
+}
+
+#[cfg(not(feature = "std"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_std_for_uint {
+	($name: ident, $n_words: tt) => {};
+}
+
+#[cfg(feature = "zeroize")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_zeroize_for_uint {
+	($name: ident) => {
+		impl $crate::zeroize::Zeroize for $name {
+			fn zeroize(&mut self) {
+				$crate::zeroize::Zeroize::zeroize(&mut self.0);
+			}
+		}
+	};
+}
+
+#[cfg(not(feature = "zeroize"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_zeroize_for_uint {
+	($name: ident) => {};
+}
+
+#[cfg(feature = "subtle")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_subtle_for_uint {
+	($name: ident) => {
+		impl $crate::subtle::ConstantTimeEq for $name {
+			fn ct_eq(&self, other: &Self) -> $crate::subtle::Choice {
+				$crate::subtle::ConstantTimeEq::ct_eq(&self.0[..], &other.0[..])
+			}
+		}
+
+		impl $crate::subtle::ConditionallySelectable for $name {
+			fn conditional_select(a: &Self, b: &Self, choice: $crate::subtle::Choice) -> Self {
+				let mut ret = Self::zero();
+				for ((r, a), b) in ret.0.iter_mut().zip(a.0.iter()).zip(b.0.iter()) {
+					*r = $crate::subtle::ConditionallySelectable::conditional_select(a, b, choice);
+				}
+				ret
+			}
+		}
+	};
+}
+
+#[cfg(not(feature = "subtle"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_subtle_for_uint {
+	($name: ident) => {};
+}
+
+#[cfg(feature = "bytemuck")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_bytemuck_for_uint {
+	($name: ident) => {
+		// SAFETY: the type is a `#[repr(C)]` wrapper of a `u64` array, so it has no
+		// padding and every bit pattern is valid. Only on little-endian targets are
+		// its bytes the little-endian encoding of the number, so the impls are
+		// limited to those to keep data cast from storage portable.
+		#[cfg(target_endian = "little")]
+		unsafe impl $crate::bytemuck::Zeroable for $name {}
+		#[cfg(target_endian = "little")]
+		unsafe impl $crate::bytemuck::Pod for $name {}
+	};
+}
+
+#[cfg(not(feature = "bytemuck"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_bytemuck_for_uint {
+	($name: ident) => {};
+}
+
 #[cfg(feature = "quickcheck")]
 #[macro_export]
 #[doc(hidden)]
@@ -0,0 +1,234 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::{convert::TryFrom, str::FromStr};
+use uint::{construct_int, construct_uint, FromDecStrErr};
+
+construct_uint! {
+	pub struct U128(2);
+}
+
+construct_uint! {
+	pub struct U256(4);
+}
+
+construct_int! {
+	pub struct I128(U128);
+}
+
+construct_int! {
+	pub struct I256(U256);
+}
+
+#[test]
+fn min_max() {
+	assert_eq!(I256::MIN.into_raw(), U256([0, 0, 0, 1 << 63]));
+	assert_eq!(I256::MAX.into_raw(), U256([u64::MAX, u64::MAX, u64::MAX, u64::MAX >> 1]));
+	assert_eq!(I128::MIN, I128::from(i128::MIN));
+	assert_eq!(I128::MAX, I128::from(i128::MAX));
+	assert_eq!(I256::minus_one().into_raw(), U256::MAX);
+	assert!(I256::MIN < I256::MAX);
+}
+
+#[test]
+fn const_constructors() {
+	const MINUS_ONE: I256 = I256::minus_one();
+	const RAW: U256 = MINUS_ONE.into_raw();
+	assert_eq!(RAW, U256::MAX);
+	assert_eq!(I256::from_raw(RAW), I256::from(-1));
+}
+
+#[test]
+fn from_primitives() {
+	assert_eq!(I256::from(-1i8), I256::minus_one());
+	assert_eq!(I256::from(-2i64).into_raw(), U256::MAX - 1);
+	assert_eq!(I256::from(i128::MIN).into_raw(), U256([0, 1 << 63, u64::MAX, u64::MAX]));
+	assert_eq!(I256::from(u64::MAX).into_raw(), U256::from(u64::MAX));
+	assert_eq!(I256::from(42u8), I256::from(42i32));
+}
+
+#[test]
+fn try_into_primitives() {
+	assert_eq!(i128::try_from(I256::from(i128::MIN)), Ok(i128::MIN));
+	assert_eq!(i128::try_from(I256::from(i128::MAX)), Ok(i128::MAX));
+	assert!(i128::try_from(I256::from(i128::MAX) + 1).is_err());
+	assert!(i128::try_from(I256::from(i128::MIN) - 1).is_err());
+	assert_eq!(i64::try_from(I256::from(-5)), Ok(-5));
+	assert!(i8::try_from(I256::from(-129)).is_err());
+	assert_eq!(u64::try_from(I256::from(u64::MAX)), Ok(u64::MAX));
+	assert!(u64::try_from(I256::from(-1)).is_err());
+	assert!(u128::try_from(I256::from(-1)).is_err());
+	assert_eq!(u128::try_from(I256::from(7)), Ok(7));
+}
+
+#[test]
+fn unsigned_conversions() {
+	assert_eq!(I256::try_from(U256::from(5)), Ok(I256::from(5)));
+	assert!(I256::try_from(U256::MAX).is_err());
+	assert!(I256::try_from(I256::MIN.into_raw()).is_err());
+	assert_eq!(U256::try_from(I256::MAX), Ok(I256::MAX.into_raw()));
+	assert!(U256::try_from(I256::from(-1)).is_err());
+
+	// raw conversions are lossless both ways
+	assert_eq!(I256::from_raw(U256::MAX), I256::minus_one());
+	assert_eq!(I256::from_raw(I256::MIN.into_raw()), I256::MIN);
+}
+
+#[test]
+fn sign() {
+	assert!(I256::from(-3).is_negative());
+	assert!(!I256::zero().is_negative());
+	assert!(!I256::zero().is_positive());
+	assert!(I256::from(3).is_positive());
+	assert_eq!(I256::from(-3).signum(), I256::minus_one());
+	assert_eq!(I256::zero().signum(), I256::zero());
+	assert_eq!(I256::MAX.signum(), I256::one());
+}
+
+#[test]
+fn comparison() {
+	let mut values = vec![I256::from(3), I256::MIN, I256::from(-1), I256::MAX, I256::zero(), I256::from(-100)];
+	values.sort();
+	assert_eq!(values, vec![I256::MIN, I256::from(-100), I256::from(-1), I256::zero(), I256::from(3), I256::MAX]);
+}
+
+#[test]
+fn add_sub() {
+	assert_eq!(I256::from(-5) + 3, I256::from(-2));
+	assert_eq!(I256::from(5) - 7, I256::from(-2));
+	assert_eq!(I256::from(-5) - (-7), I256::from(2));
+
+	assert_eq!(I256::MAX.overflowing_add(I256::one()), (I256::MIN, true));
+	assert_eq!(I256::MIN.overflowing_sub(I256::one()), (I256::MAX, true));
+	assert_eq!(I256::MIN.overflowing_add(I256::MAX), (I256::minus_one(), false));
+	assert_eq!(I256::MAX.checked_add(I256::one()), None);
+	assert_eq!(I256::MIN.checked_sub(I256::one()), None);
+	assert_eq!(I256::MAX.saturating_add(I256::one()), I256::MAX);
+	assert_eq!(I256::MIN.saturating_add(I256::minus_one()), I256::MIN);
+	assert_eq!(I256::MIN.saturating_sub(I256::one()), I256::MIN);
+	assert_eq!(I256::MAX.saturating_sub(I256::minus_one()), I256::MAX);
+	assert_eq!(I256::MAX.wrapping_add(I256::one()), I256::MIN);
+}
+
+#[test]
+#[should_panic]
+fn add_overflow_panic() {
+	let _ = I256::MAX + 1;
+}
+
+#[test]
+fn mul() {
+	assert_eq!(I256::from(-6) * 7, I256::from(-42));
+	assert_eq!(I256::from(-6) * -7, I256::from(42));
+	assert_eq!(I128::from(i64::MIN) * I128::from(i64::MIN), I128::from(i64::MIN as i128 * i64::MIN as i128));
+
+	assert_eq!(I256::MIN.overflowing_mul(I256::one()), (I256::MIN, false));
+	assert_eq!(I256::MIN.overflowing_mul(I256::minus_one()), (I256::MIN, true));
+	assert_eq!((I256::MIN >> 1).overflowing_mul(I256::from(2)), (I256::MIN, false));
+	assert_eq!((I256::MAX >> 1).checked_mul(I256::from(3)), None);
+	assert_eq!(I256::MAX.saturating_mul(I256::from(-2)), I256::MIN);
+	assert_eq!(I256::MIN.saturating_mul(I256::from(-2)), I256::MAX);
+}
+
+#[test]
+fn div_rem() {
+	// Truncated division, like the primitive types.
+	for &(a, b) in &[(7i128, 2i128), (-7, 2), (7, -2), (-7, -2), (0, 5), (i128::MIN, 3), (i128::MAX, -9)] {
+		let (q, r) = I128::from(a).div_mod(I128::from(b));
+		assert_eq!(q, I128::from(a / b), "{} / {}", a, b);
+		assert_eq!(r, I128::from(a % b), "{} % {}", a, b);
+	}
+
+	assert_eq!(I256::MIN.overflowing_div(I256::minus_one()), (I256::MIN, true));
+	assert_eq!(I256::MIN.overflowing_rem(I256::minus_one()), (I256::zero(), true));
+	assert_eq!(I256::MIN.checked_div(I256::minus_one()), None);
+	assert_eq!(I256::one().checked_div(I256::zero()), None);
+	assert_eq!(I256::one().checked_rem(I256::zero()), None);
+	assert_eq!(I256::MIN.saturating_div(I256::minus_one()), I256::MAX);
+	assert_eq!(I256::MIN.wrapping_div(I256::minus_one()), I256::MIN);
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn div_by_zero_panic() {
+	let _ = I256::one() / I256::zero();
+}
+
+#[test]
+fn neg_abs() {
+	assert_eq!(-I256::from(5), I256::from(-5));
+	assert_eq!(I256::MIN.overflowing_neg(), (I256::MIN, true));
+	assert_eq!(I256::MIN.checked_neg(), None);
+	assert_eq!(I256::MIN.saturating_neg(), I256::MAX);
+	assert_eq!(I256::MIN.checked_abs(), None);
+	assert_eq!(I256::MIN.saturating_abs(), I256::MAX);
+	assert_eq!(I256::MIN.wrapping_abs(), I256::MIN);
+	assert_eq!(I256::MIN.unsigned_abs(), U256::one() << 255);
+	assert_eq!(I256::from(-9).abs(), I256::from(9));
+	assert_eq!(I256::from(-9).abs_diff(I256::from(9)), U256::from(18));
+	assert_eq!(I256::MIN.abs_diff(I256::MAX), U256::MAX);
+}
+
+#[test]
+fn pow() {
+	assert_eq!(I256::from(-3).pow(3), I256::from(-27));
+	assert_eq!(I256::from(-3).pow(4), I256::from(81));
+	assert_eq!(I256::from(-2).pow(255), I256::MIN);
+	assert_eq!(I256::from(2).checked_pow(255), None);
+	assert_eq!(I256::from(-2).checked_pow(256), None);
+	assert_eq!(I256::from(-2).saturating_pow(257), I256::MIN);
+	assert_eq!(I256::from(-2).saturating_pow(256), I256::MAX);
+	assert_eq!(I256::from(7).pow(0), I256::one());
+}
+
+#[test]
+fn shifts() {
+	assert_eq!(I256::from(-16) >> 2, I256::from(-4));
+	assert_eq!(I256::from(-1) >> 255, I256::minus_one());
+	assert_eq!(I256::from(-1) >> 256, I256::minus_one());
+	assert_eq!(I256::from(16) >> 2, I256::from(4));
+	assert_eq!(I256::from(16) >> 300, I256::zero());
+	assert_eq!(I256::from(-7) >> 1, I256::from(-4));
+	assert_eq!(I256::from(-3) << 2, I256::from(-12));
+	assert_eq!(I256::one() << 255, I256::MIN);
+}
+
+#[test]
+fn sign_extend() {
+	assert_eq!(I256::from(0xff).sign_extend(8), I256::minus_one());
+	assert_eq!(I256::from(0x7f).sign_extend(8), I256::from(0x7f));
+	assert_eq!(I256::from(0x1ff).sign_extend(8), I256::minus_one());
+	assert_eq!(I256::from(0x180).sign_extend(8), I256::from(-128));
+	assert_eq!(I256::from(0x8000).sign_extend(16), I256::from(i16::MIN));
+	assert_eq!(I256::MIN.sign_extend(256), I256::MIN);
+}
+
+#[test]
+fn display_and_parse() {
+	assert_eq!(I256::zero().to_string(), "0");
+	assert_eq!(I256::from(-1234567890123456789i64).to_string(), "-1234567890123456789");
+	assert_eq!(I128::MIN.to_string(), i128::MIN.to_string());
+	assert_eq!(I128::MAX.to_string(), i128::MAX.to_string());
+	assert_eq!(format!("{:>6}", I256::from(-42)), "   -42");
+	assert_eq!(format!("{:+}", I256::from(42)), "+42");
+	assert_eq!(format!("{:?}", I256::from(-42)), "-42");
+	assert_eq!(format!("{:x}", I128::from(-1)), format!("{:x}", -1i128));
+
+	assert_eq!(I256::from_str("-42"), Ok(I256::from(-42)));
+	assert_eq!(I256::from_str("+42"), Ok(I256::from(42)));
+	assert_eq!(I128::from_str(&i128::MIN.to_string()), Ok(I128::MIN));
+	assert_eq!(I128::from_str(&i128::MAX.to_string()), Ok(I128::MAX));
+	assert_eq!(I128::from_str("170141183460469231731687303715884105728"), Err(FromDecStrErr::InvalidLength));
+	assert_eq!(I128::from_str("-170141183460469231731687303715884105729"), Err(FromDecStrErr::InvalidLength));
+	assert_eq!(I256::from_str("-"), Err(FromDecStrErr::InvalidCharacter));
+	assert_eq!(I256::from_str("--1"), Err(FromDecStrErr::InvalidCharacter));
+	assert_eq!(I256::from_str("1a"), Err(FromDecStrErr::InvalidCharacter));
+
+	let s = I256::MIN.to_string();
+	assert_eq!(I256::from_str(&s), Ok(I256::MIN));
+}
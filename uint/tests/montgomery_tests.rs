@@ -0,0 +1,157 @@
+// Copyright 2020 Parity Technologies
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use uint::{construct_montgomery, construct_uint};
+
+construct_uint! {
+	pub struct U64(1);
+}
+
+construct_uint! {
+	pub struct U256(4);
+}
+
+construct_uint! {
+	pub struct U448(7);
+}
+
+// 2^255 - 19
+const P25519: U256 = U256([0xffff_ffff_ffff_ffed, u64::MAX, u64::MAX, 0x7fff_ffff_ffff_ffff]);
+
+// 2^448 - 2^224 - 1
+const P448: U448 = U448([u64::MAX, u64::MAX, u64::MAX, 0xffff_fffe_ffff_ffff, u64::MAX, u64::MAX, u64::MAX]);
+
+// not a prime: 3 * 5 * 7
+const M105: U64 = U64([105]);
+
+// the largest odd value, so the top limb is full
+const MAX_ODD: U256 = U256::MAX;
+
+construct_montgomery! {
+	pub struct Fp25519(U256, P25519);
+}
+
+construct_montgomery! {
+	pub struct Fp448(U448, P448);
+}
+
+construct_montgomery! {
+	pub struct Z105(U64, M105);
+}
+
+construct_montgomery! {
+	pub struct ZMax(U256, MAX_ODD);
+}
+
+fn samples() -> Vec<U256> {
+	vec![
+		U256::zero(),
+		U256::one(),
+		U256::from(2),
+		U256::from(u64::MAX),
+		U256::from(u128::MAX),
+		P25519 - 1,
+		P25519,
+		P25519 + 1,
+		U256::MAX - 1,
+		U256::MAX,
+		U256([0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210, 0x0f0f_0f0f_0f0f_0f0f, 0x1234_5678_9abc_def0]),
+	]
+}
+
+#[test]
+fn roundtrip() {
+	for x in samples() {
+		assert_eq!(Fp25519::from_uint(x).into_uint(), x % P25519);
+		assert_eq!(ZMax::from_uint(x).into_uint(), x % MAX_ODD);
+	}
+	assert_eq!(Fp25519::zero().into_uint(), U256::zero());
+	assert_eq!(Fp25519::one().into_uint(), U256::one());
+	assert_eq!(Fp25519::from(5u64), Fp25519::from_uint(U256::from(5)));
+	assert_eq!(U256::from(Fp25519::from(P25519 + 3)), U256::from(3));
+	assert_eq!(Fp25519::default(), Fp25519::zero());
+}
+
+#[test]
+fn arithmetic_matches_mod_ops() {
+	for a in samples() {
+		for b in samples() {
+			let (x, y) = (Fp25519::from(a), Fp25519::from(b));
+			assert_eq!((x + y).into_uint(), a.add_mod(b, P25519));
+			assert_eq!((x - y).into_uint(), a.sub_mod(b, P25519));
+			assert_eq!((x * y).into_uint(), a.mul_mod(b, P25519));
+
+			let (x, y) = (ZMax::from(a), ZMax::from(b));
+			assert_eq!((x + y).into_uint(), a.add_mod(b, MAX_ODD));
+			assert_eq!((x - y).into_uint(), a.sub_mod(b, MAX_ODD));
+			assert_eq!((x * y).into_uint(), a.mul_mod(b, MAX_ODD));
+		}
+		let x = Fp25519::from(a);
+		assert_eq!((-x).into_uint(), U256::zero().sub_mod(a, P25519));
+		assert_eq!(x.square(), x * x);
+		assert_eq!(x.double(), x + x);
+		assert_eq!(x.pow(U256::from(5)).into_uint(), a.pow_mod(U256::from(5), P25519));
+	}
+}
+
+#[test]
+fn assign_ops() {
+	let mut x = Fp25519::from(7u64);
+	x += Fp25519::from(3u64);
+	assert_eq!(x, Fp25519::from(10u64));
+	x -= &Fp25519::from(11u64);
+	assert_eq!(x.into_uint(), P25519 - 1);
+	x *= x;
+	assert_eq!(x, Fp25519::one());
+}
+
+#[test]
+fn inverse() {
+	for a in samples() {
+		let x = Fp25519::from(a);
+		match x.inverse() {
+			Some(inv) => assert_eq!(x * inv, Fp25519::one()),
+			None => assert!(x.is_zero()),
+		}
+	}
+
+	// Fermat's little theorem
+	let x = Fp448::from(123456789u64);
+	assert_eq!(x.pow(P448 - 2), x.inverse().unwrap());
+	assert_eq!(x.pow(P448 - 1), Fp448::one());
+
+	// not every element is invertible modulo a composite
+	assert_eq!(Z105::from(15u64).inverse(), None);
+	assert_eq!(Z105::from(2u64).inverse(), Some(Z105::from(53u64)));
+}
+
+#[test]
+fn goldilocks() {
+	let a = U448::from_dec_str(
+		"7266319213148549592069312624651389802722637449716412998930221138462587961837849108834657698574009932497007081432998131592612981427125",
+	)
+	.unwrap();
+	let b = P448 - 12345;
+	let (x, y) = (Fp448::from(a), Fp448::from(b));
+	assert_eq!((x * y).into_uint(), a.mul_mod(b, P448));
+	assert_eq!((x - y).into_uint(), a.sub_mod(b, P448));
+	assert_eq!(Fp448::from(P448 - 1).square(), Fp448::one());
+}
+
+#[test]
+fn ct_eq_bool() {
+	assert!(Fp25519::from(3u64).ct_eq_bool(&Fp25519::from(P25519 + 3)));
+	assert!(!Fp25519::from(3u64).ct_eq_bool(&Fp25519::from(4u64)));
+}
+
+#[test]
+fn formatting() {
+	assert_eq!(Fp25519::from(P25519 + 42).to_string(), "42");
+	assert_eq!(format!("{:?}", Fp25519::from(42u64)), "42");
+	assert_eq!(format!("{:x}", Fp25519::from(255u64)), "ff");
+}
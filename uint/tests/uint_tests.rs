@@ -8,7 +8,7 @@
 
 use core::{convert::TryInto, str::FromStr, u64::MAX};
 use crunchy::unroll;
-use uint::{construct_uint, overflowing, FromDecStrErr};
+use uint::{construct_uint, overflowing, FromDecStrErr, FromStrRadixErrKind};
 
 construct_uint! {
 	pub struct U256(4);
@@ -656,6 +656,68 @@ fn display_uint_zero() {
 	assert_eq!(format!("{:#032X}", s), "0x000000000000000000000000000000");
 }
 
+#[test]
+fn display_uint_binary_octal() {
+	let s = U256::from(0b1011_0110u64);
+	assert_eq!(format!("{:b}", s), "10110110");
+	assert_eq!(format!("{:o}", s), "266");
+	assert_eq!(format!("{:#b}", s), "0b10110110");
+	assert_eq!(format!("{:#o}", s), "0o266");
+	assert_eq!(format!("{:016b}", s), "0000000010110110");
+	assert_eq!(format!("{:#012b}", s), "0b0010110110");
+	assert_eq!(format!("{:>6o}", s), "   266");
+	assert_eq!(format!("{:b}", U256::zero()), "0");
+	assert_eq!(format!("{:#o}", U256::zero()), "0o0");
+
+	assert_eq!(format!("{:b}", U256::MAX), "1".repeat(256));
+	assert_eq!(format!("{:o}", U256::MAX), format!("1{}", "7".repeat(85)));
+	assert_eq!(format!("{:b}", U256::one() << 200), format!("1{}", "0".repeat(200)));
+	assert_eq!(format!("{:o}", U256::from(u128::MAX)), format!("{:o}", u128::MAX));
+}
+
+#[test]
+fn to_str_radix() {
+	let x = U256::from(u128::MAX - 12345);
+	assert_eq!(x.to_str_radix(10), x.to_string());
+	assert_eq!(x.to_str_radix(16), format!("{:x}", x));
+	assert_eq!(x.to_str_radix(2), format!("{:b}", u128::MAX - 12345));
+	assert_eq!(U256::from(35).to_str_radix(36), "z");
+	assert_eq!(U256::from(36).to_str_radix(36), "10");
+	assert_eq!(U256::from(u64::MAX).to_str_radix(36), "3w5e11264sgsf");
+	assert_eq!(U256::from(3u64.pow(40)).to_str_radix(3), format!("1{}", "0".repeat(40)));
+	assert_eq!(U256::zero().to_str_radix(7), "0");
+	assert_eq!(U256::MAX.to_str_radix(10), U256::MAX.to_string());
+}
+
+#[test]
+#[should_panic(expected = "radix must be in the range 2..=36")]
+fn to_str_radix_unsupported() {
+	U256::one().to_str_radix(37);
+}
+
+#[test]
+fn from_str_radix() {
+	assert_eq!(U256::from_str_radix("10110110", 2).unwrap(), U256::from(0b1011_0110));
+	assert_eq!(U256::from_str_radix("266", 8).unwrap(), U256::from(0o266));
+	assert_eq!(U256::from_str_radix("3w5e11264sgsf", 36).unwrap(), U256::from(u64::MAX));
+	assert_eq!(U256::from_str_radix("3W5E11264SGSF", 36).unwrap(), U256::from(u64::MAX));
+	assert_eq!(U256::from_str_radix(&"1".repeat(256), 2).unwrap(), U256::MAX);
+	assert_eq!(U256::from_str_radix(&"f".repeat(64), 16).unwrap(), U256::MAX);
+	assert_eq!(U256::from_str_radix("12345", 10).unwrap(), U256::from(12345));
+
+	assert_eq!(U256::from_str_radix("102", 2).unwrap_err().kind(), FromStrRadixErrKind::InvalidCharacter);
+	assert_eq!(U256::from_str_radix("0b101", 2).unwrap_err().kind(), FromStrRadixErrKind::InvalidCharacter);
+	assert_eq!(U256::from_str_radix(&"1".repeat(257), 2).unwrap_err().kind(), FromStrRadixErrKind::InvalidLength);
+	// the multiplication fits but adding the last digit overflows
+	let max = U256::MAX.to_str_radix(36);
+	assert_eq!(
+		U256::from_str_radix(&format!("{}z", &max[..max.len() - 1]), 36).unwrap_err().kind(),
+		FromStrRadixErrKind::InvalidLength
+	);
+	assert_eq!(U256::from_str_radix("1", 1).unwrap_err().kind(), FromStrRadixErrKind::UnsupportedRadix);
+	assert_eq!(U256::from_str_radix("1", 37).unwrap_err().kind(), FromStrRadixErrKind::UnsupportedRadix);
+}
+
 #[test]
 fn u512_multi_adds() {
 	let (result, _) = U512([0, 0, 0, 0, 0, 0, 0, 0]).overflowing_add(U512([0, 0, 0, 0, 0, 0, 0, 0]));
@@ -1192,6 +1254,334 @@ fn bit_assign() {
 	check(U256::MAX, U256::zero());
 }
 
+#[test]
+fn const_arithmetic() {
+	const TWO_POW_255: U256 = U256::one().const_shl(255);
+	const REWARD: U256 = U256::from_u64(5_000_000_000).const_mul(U256::from_u64(1_000_000_000));
+	const HALF_MAX: U256 = U256::MAX.const_shr(1);
+	const DIFF: U256 = TWO_POW_255.const_sub(U256::one());
+	const SUM: U256 = HALF_MAX.const_add(U256::one());
+
+	assert_eq!(TWO_POW_255, U256::one() << 255);
+	assert_eq!(REWARD, U256::from(5_000_000_000_000_000_000u128));
+	assert_eq!(HALF_MAX, U256::MAX >> 1);
+	assert_eq!(DIFF, HALF_MAX);
+	assert_eq!(SUM, TWO_POW_255);
+
+	assert_eq!(U256::MAX.const_overflowing_add(U256::one()), U256::MAX.overflowing_add(U256::one()));
+	assert_eq!(U256::zero().const_overflowing_sub(U256::one()), U256::zero().overflowing_sub(U256::one()));
+	assert_eq!(TWO_POW_255.const_overflowing_mul(U256::from(2)), TWO_POW_255.overflowing_mul(U256::from(2)));
+	assert_eq!(U256::MAX.const_overflowing_mul(U256::MAX), U256::MAX.overflowing_mul(U256::MAX));
+	let a = U256([0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210, 0, 0]);
+	let b = U256([0xffff_ffff_0000_0001, 0x1111, 0, 0]);
+	assert_eq!(a.const_overflowing_mul(b), a.overflowing_mul(b));
+	assert_eq!(a.const_overflowing_mul(b.const_shl(128)), a.overflowing_mul(b << 128));
+
+	for shift in [0, 1, 63, 64, 65, 127, 128, 200, 255, 256, 1000] {
+		assert_eq!(a.const_shl(shift), a << shift, "{}", shift);
+		assert_eq!(U256::MAX.const_shr(shift), U256::MAX >> shift, "{}", shift);
+	}
+
+	assert_eq!(a.const_cmp(&b), a.cmp(&b));
+	assert_eq!(b.const_cmp(&a), b.cmp(&a));
+	assert_eq!(a.const_cmp(&a), core::cmp::Ordering::Equal);
+	assert!(a.const_eq(&a));
+	assert!(!a.const_eq(&b));
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn const_add_overflow() {
+	let _ = U256::MAX.const_add(U256::one());
+}
+
+#[test]
+fn from_str_const() {
+	const DEC: U256 = U256::from_str_const("1_000_000_000_000_000_000");
+	const HEX: U256 = U256::from_str_const("0xde0b6b3a7640000");
+	const MAX: U256 =
+		U256::from_str_const("115792089237316195423570985008687907853269984665640564039457584007913129639935");
+	const MAX_HEX: U256 = U256::from_str_const("0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
+
+	assert_eq!(DEC, U256::exp10(18));
+	assert_eq!(HEX, U256::exp10(18));
+	assert_eq!(MAX, U256::MAX);
+	assert_eq!(MAX_HEX, U256::MAX);
+	assert_eq!(U256::from_str_const("0"), U256::zero());
+	assert_eq!(U256::from_str_const("0x0"), U256::zero());
+}
+
+#[test]
+#[should_panic(expected = "number too large")]
+fn from_str_const_too_large() {
+	let _ = U256::from_str_const("115792089237316195423570985008687907853269984665640564039457584007913129639936");
+}
+
+#[test]
+#[should_panic(expected = "invalid character")]
+fn from_str_const_invalid_character() {
+	let _ = U256::from_str_const("12a");
+}
+
+#[test]
+#[should_panic(expected = "empty number")]
+fn from_str_const_empty() {
+	let _ = U256::from_str_const("0x");
+}
+
+#[test]
+#[should_panic(expected = "empty number")]
+fn from_str_const_only_separators() {
+	let _ = U256::from_str_const("_");
+}
+
+#[test]
+#[should_panic(expected = "empty number")]
+fn from_str_const_hex_only_separators() {
+	let _ = U256::from_str_const("0x_");
+}
+
+#[test]
+fn const_from_big_endian() {
+	const BYTES: [u8; 3] = [0x01, 0x02, 0x03];
+	const BE: U256 = U256::from_big_endian(&BYTES);
+	const LE: U256 = U256::from_little_endian(&BYTES);
+	assert_eq!(BE, U256::from(0x010203));
+	assert_eq!(LE, U256::from(0x030201));
+}
+
+#[test]
+fn gcd_lcm() {
+	assert_eq!(U256::zero().gcd(U256::zero()), U256::zero());
+	assert_eq!(U256::zero().gcd(U256::from(12)), U256::from(12));
+	assert_eq!(U256::from(12).gcd(U256::zero()), U256::from(12));
+	assert_eq!(U256::from(12).gcd(U256::from(18)), U256::from(6));
+	assert_eq!(U256::from(17).gcd(U256::from(31)), U256::one());
+	assert_eq!((U256::one() << 200).gcd(U256::from(96)), U256::from(32));
+	assert_eq!(U256::MAX.gcd(U256::MAX), U256::MAX);
+	// 2^256 - 1 = 3 * 5 * 17 * ...
+	assert_eq!(U256::MAX.gcd(U256::from(255)), U256::from(255));
+
+	assert_eq!(U256::from(4).lcm(U256::from(6)), U256::from(12));
+	assert_eq!(U256::zero().lcm(U256::from(6)), U256::zero());
+	assert_eq!(U256::MAX.lcm(U256::from(255)), U256::MAX);
+	assert_eq!(U256::MAX.checked_lcm(U256::from(2)), None);
+	assert_eq!((U256::one() << 255).checked_lcm(U256::from(2)), Some(U256::one() << 255));
+}
+
+#[test]
+fn integer_nth_root() {
+	assert_eq!(U256::zero().integer_nth_root(3), U256::zero());
+	assert_eq!(U256::one().integer_nth_root(3), U256::one());
+	assert_eq!(U256::from(26).integer_nth_root(3), U256::from(2));
+	assert_eq!(U256::from(27).integer_nth_root(3), U256::from(3));
+	assert_eq!(U256::from(28).integer_nth_root(3), U256::from(3));
+	assert_eq!(U256::from(1000).integer_nth_root(1), U256::from(1000));
+	assert_eq!(U256::from(1000).integer_nth_root(200), U256::one());
+	assert_eq!(U256::MAX.integer_nth_root(2), U256::MAX.integer_sqrt());
+	assert_eq!(U256::MAX.integer_nth_root(4), U256::from(u64::MAX));
+	assert_eq!(U256::MAX.integer_nth_root(255), U256::from(2));
+	assert_eq!(U256::MAX.integer_nth_root(256), U256::one());
+	assert_eq!((U256::one() << 255).integer_nth_root(255), U256::from(2));
+	let x = U256::from(123456789u64);
+	assert_eq!(x.pow(5.into()).integer_nth_root(5), x);
+	assert_eq!((x.pow(5.into()) - 1).integer_nth_root(5), x - 1);
+}
+
+#[test]
+#[should_panic(expected = "zeroth root is undefined")]
+fn integer_zeroth_root() {
+	let _ = U256::from(2).integer_nth_root(0);
+}
+
+#[test]
+fn ilog() {
+	assert_eq!(U256::one().ilog2(), 0);
+	assert_eq!(U256::from(1023).ilog2(), 9);
+	assert_eq!(U256::from(1024).ilog2(), 10);
+	assert_eq!(U256::MAX.ilog2(), 255);
+	assert_eq!(U256::zero().checked_ilog2(), None);
+
+	assert_eq!(U256::one().ilog10(), 0);
+	assert_eq!(U256::from(999).ilog10(), 2);
+	assert_eq!(U256::from(1000).ilog10(), 3);
+	assert_eq!(U256::exp10(19).ilog10(), 19);
+	assert_eq!((U256::exp10(19) - 1).ilog10(), 18);
+	assert_eq!(U256::exp10(77).ilog10(), 77);
+	assert_eq!(U256::MAX.ilog10(), 77);
+	assert_eq!(U256::zero().checked_ilog10(), None);
+
+	assert_eq!(U256::from(80).ilog(U256::from(3)), 3);
+	assert_eq!(U256::from(81).ilog(U256::from(3)), 4);
+	assert_eq!(U256::MAX.ilog(U256::from(16)), 63);
+	assert_eq!(U256::MAX.ilog(U256::MAX), 1);
+	assert_eq!((U256::MAX - 1).ilog(U256::MAX), 0);
+	assert_eq!(U256::from(5).checked_ilog(U256::one()), None);
+	assert_eq!(U256::zero().checked_ilog(U256::from(3)), None);
+}
+
+#[test]
+#[should_panic(expected = "argument of integer logarithm must be positive")]
+fn ilog2_of_zero() {
+	let _ = U256::zero().ilog2();
+}
+
+#[test]
+fn powers_of_two() {
+	assert!(!U256::zero().is_power_of_two());
+	assert!(U256::one().is_power_of_two());
+	assert!((U256::one() << 255).is_power_of_two());
+	assert!(!U256::from(6).is_power_of_two());
+
+	assert_eq!(U256::zero().next_power_of_two(), U256::one());
+	assert_eq!(U256::one().next_power_of_two(), U256::one());
+	assert_eq!(U256::from(5).next_power_of_two(), U256::from(8));
+	assert_eq!(U256::from(u64::MAX).next_power_of_two(), U256::one() << 64);
+	assert_eq!((U256::one() << 255).next_power_of_two(), U256::one() << 255);
+	assert_eq!(((U256::one() << 255) + 1).checked_next_power_of_two(), None);
+}
+
+#[test]
+fn bit_manipulation() {
+	assert_eq!(U256::zero().count_ones(), 0);
+	assert_eq!(U256::MAX.count_ones(), 256);
+	assert_eq!(U256::from(0b1011).count_ones(), 3);
+	assert_eq!(U256::from(0b1011).count_zeros(), 253);
+
+	let x = U256([0x8000_0000_0000_0001, 0, 0, 0x8000_0000_0000_0000]);
+	assert_eq!(x.rotate_left(1), U256([3, 1, 0, 0]));
+	assert_eq!(x.rotate_left(257), x.rotate_left(1));
+	assert_eq!(x.rotate_right(1), U256([0x4000_0000_0000_0000, 0, 0, 0xc000_0000_0000_0000]));
+	assert_eq!(x.rotate_left(0), x);
+	assert_eq!(x.rotate_right(256), x);
+	assert_eq!(x.rotate_left(100).rotate_right(100), x);
+
+	assert_eq!(U256::one().reverse_bits(), U256::one() << 255);
+	assert_eq!(x.reverse_bits(), U256([1, 0, 0, 0x8000_0000_0000_0001]));
+	assert_eq!(x.reverse_bits().reverse_bits(), x);
+}
+
+#[test]
+fn constant_time_eq() {
+	let x = U256([1, 2, 3, 4]);
+	assert!(x.ct_eq_bool(&x));
+	assert!(!x.ct_eq_bool(&U256([1, 2, 3, 5])));
+	assert!(!x.ct_eq_bool(&U256([0, 2, 3, 4])));
+	assert!(U256::zero().ct_eq_bool(&U256::zero()));
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn zeroize() {
+	use zeroize::Zeroize;
+
+	let mut x = U256::MAX;
+	x.zeroize();
+	assert!(x.is_zero());
+}
+
+#[cfg(all(feature = "bytemuck", target_endian = "little"))]
+#[test]
+fn bytemuck() {
+	let words = [1u64, 0, 0, 0, u64::MAX, 2, 0, 0];
+	let numbers: &[U256] = bytemuck::cast_slice(&words);
+	assert_eq!(numbers, &[U256::one(), (U256::from(2) << 64) | U256::from(u64::MAX)]);
+
+	let mut bytes = [0u8; 32];
+	U256::from(0x0102_0304).to_little_endian(&mut bytes);
+	assert_eq!(bytemuck::bytes_of(&U256::from(0x0102_0304)), &bytes[..]);
+	assert_eq!(<U256 as bytemuck::Zeroable>::zeroed(), U256::zero());
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn subtle() {
+	use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+	let x = U256([1, 2, 3, 4]);
+	// method syntax resolves to the trait, not to an inherent method
+	let eq: Choice = x.ct_eq(&x);
+	assert!(bool::from(eq));
+	assert!(!bool::from(ConstantTimeEq::ct_eq(&x, &U256::MAX)));
+	assert_eq!(U256::conditional_select(&x, &U256::MAX, Choice::from(0)), x);
+	assert_eq!(U256::conditional_select(&x, &U256::MAX, Choice::from(1)), U256::MAX);
+}
+
+#[test]
+fn mod_arithmetic() {
+	let p = U256::from_dec_str("38873241744847760218045702002058062581688990428170398542849190507947196700873").unwrap();
+
+	assert_eq!((p - 1).add_mod(p + 1, p), U256::zero());
+	assert_eq!((p - 1).add_mod(p - 1, p), p - 2);
+	assert_eq!(U256::MAX.add_mod(U256::MAX, U256::MAX - 1), U256::from(2));
+	assert_eq!(U256::one().sub_mod(U256::from(2), p), p - 1);
+	assert_eq!(U256::from(5).sub_mod(U256::from(3), p), U256::from(2));
+	assert_eq!(U256::zero().sub_mod(U256::MAX, U256::MAX - 1), U256::MAX - 2);
+
+	assert_eq!((p - 1).mul_mod(U256::from(3), p), p - 3);
+	assert_eq!((p - 1).mul_mod(p - 1, p), U256::one());
+	// 2^256 = 2 (mod 7)
+	assert_eq!(U256::MAX.mul_mod(U256::MAX, U256::from(7)), U256::one());
+	assert_eq!(U256::MAX.mul_mod(U256::MAX, U256::MAX), U256::zero());
+	assert_eq!(U256::MAX.mul_mod(U256::MAX - 1, U256::MAX - 2), U256::from(2));
+
+	// Fermat's little theorem
+	assert_eq!(U256::from(123456789).pow_mod(p - 1, p), U256::one());
+	assert_eq!(U256::from(3).pow_mod(U256::from(5), U256::from(7)), U256::from(5));
+	assert_eq!(U256::from(3).pow_mod(U256::zero(), U256::one()), U256::zero());
+
+	let x = U256::from_dec_str("1234567890123456789012345678901234567890").unwrap();
+	let inv = x.inv_mod(p).unwrap();
+	assert_eq!(x.mul_mod(inv, p), U256::one());
+	assert_eq!(inv, x.pow_mod(p - 2, p));
+	assert_eq!(U256::from(3).inv_mod(U256::from(7)), Some(U256::from(5)));
+	assert_eq!(U256::from(6).inv_mod(U256::from(9)), None);
+	assert_eq!(U256::zero().inv_mod(p), None);
+	assert_eq!(U256::from(5).inv_mod(U256::one()), Some(U256::zero()));
+}
+
+#[test]
+fn mul_mod_matches_wide_rem() {
+	fn widen(x: U256) -> U512 {
+		let mut words = [0u64; 8];
+		words[..4].copy_from_slice(&x.0);
+		U512(words)
+	}
+
+	let values = [
+		U256::MAX,
+		U256::MAX - 1,
+		U256::one() << 255,
+		U256::from(u64::MAX),
+		U256::from(u128::MAX),
+		U256::from_dec_str("38873241744847760218045702002058062581688990428170398542849190507947196700873").unwrap(),
+		U256([0x0123_4567_89ab_cdef, 0, 0xfedc_ba98_7654_3210, 1]),
+		U256([u64::MAX, 0, 0, 0x8000_0000_0000_0000]),
+		U256::from(3),
+	];
+	for &a in &values {
+		for &b in &values {
+			for &m in &values {
+				let expected = (widen(a) * widen(b)) % widen(m);
+				assert_eq!(widen(a.mul_mod(b, m)), expected, "{} * {} % {}", a, b, m);
+			}
+		}
+	}
+}
+
+#[test]
+fn widening_mul() {
+	assert_eq!(U256::MAX.widening_mul(U256::MAX), (U256::one(), U256::MAX - 1));
+	assert_eq!(U256::from(6).widening_mul(U256::from(7)), (U256::from(42), U256::zero()));
+	assert_eq!((U256::one() << 255).widening_mul(U256::from(4)), (U256::zero(), U256::from(2)));
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn mul_mod_by_zero() {
+	let _ = U256::from(3).mul_mod(U256::from(5), U256::zero());
+}
+
 #[cfg(feature = "quickcheck")]
 pub mod laws {
 	use super::construct_uint;
@@ -1376,6 +1766,86 @@ pub mod laws {
 						)
 					}
 				}
+
+				quickcheck! {
+					fn gcd_divides(x: $uint_ty, y: $uint_ty) -> TestResult {
+						if x.is_zero() || y.is_zero() {
+							return TestResult::discard();
+						}
+
+						let g = x.gcd(y);
+						TestResult::from_bool(
+							(x % g).is_zero() && (y % g).is_zero() && (x / g).gcd(y / g) == $uint_ty::one()
+						)
+					}
+				}
+
+				quickcheck! {
+					fn cube_root(x: $uint_ty) -> TestResult {
+						let r = x.integer_nth_root(3);
+						let higher = r + 1;
+						match higher.checked_pow($uint_ty::from(3)) {
+							Some(y) => TestResult::from_bool(r.pow($uint_ty::from(3)) <= x && y > x),
+							None => TestResult::from_bool(r.pow($uint_ty::from(3)) <= x),
+						}
+					}
+				}
+
+				quickcheck! {
+					fn mul_mod_is_rem_of_mul(x: $uint_ty, y: $uint_ty, m: $uint_ty) -> TestResult {
+						if m.is_zero() || x.overflowing_mul(y).1 {
+							return TestResult::discard();
+						}
+
+						TestResult::from_bool(
+							x.mul_mod(y, m) == (x * y) % m
+						)
+					}
+				}
+
+				quickcheck! {
+					fn str_radix_roundtrip(x: $uint_ty, radix: u32) -> bool {
+						let radix = radix % 35 + 2;
+						<$uint_ty>::from_str_radix(&x.to_str_radix(radix), radix).unwrap() == x
+					}
+				}
+
+				quickcheck! {
+					fn add_sub_mod_roundtrip(x: $uint_ty, y: $uint_ty, m: $uint_ty) -> TestResult {
+						if m.is_zero() {
+							return TestResult::discard();
+						}
+
+						TestResult::from_bool(
+							x.add_mod(y, m).sub_mod(y, m) == x % m
+						)
+					}
+				}
+
+				quickcheck! {
+					fn pow_mod_square(x: $uint_ty, m: $uint_ty) -> TestResult {
+						if m.is_zero() {
+							return TestResult::discard();
+						}
+
+						TestResult::from_bool(
+							x.pow_mod($uint_ty::from(2), m) == x.mul_mod(x, m)
+						)
+					}
+				}
+
+				quickcheck! {
+					fn inv_mod_is_inverse(x: $uint_ty, m: $uint_ty) -> TestResult {
+						if m.is_zero() {
+							return TestResult::discard();
+						}
+
+						match x.inv_mod(m) {
+							Some(inv) => TestResult::from_bool(x.mul_mod(inv, m) == $uint_ty::one() % m),
+							None => TestResult::discard(),
+						}
+					}
+				}
 			}
 		}
 	}